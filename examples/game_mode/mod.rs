@@ -0,0 +1,174 @@
+use world::{Side, World};
+
+/// The winner of a match.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Winner {
+    Player(usize),
+    Team(usize),
+    Species(usize),
+    /// The attackers of a defended planet.
+    Invaders,
+    /// The players defending a planet.
+    Defenders,
+}
+
+/// Decides scoring, respawn policy and the winner of a match.
+pub trait GameMode {
+    /// Returns the score of player.
+    fn score(&self, world: &World, player_id: usize) -> u32;
+    /// Returns `true` if a dead player can respawn.
+    fn can_respawn(&self, world: &World, player_id: usize) -> bool;
+    /// Returns the winners of the match, if decided.
+    fn winners(&self, world: &World) -> Vec<Winner>;
+    /// Returns the planet and location where player spawns,
+    /// or `None` to spawn on the spawning planet.
+    fn spawn_point(&self, _world: &World, _player_id: usize) -> Option<(usize, u8)> {
+        None
+    }
+    /// Returns the planet attacked by waves of invaders.
+    fn defended_planet(&self) -> Option<usize> {
+        None
+    }
+    /// Returns `true` if teams capture spaceports and score by holding them.
+    fn captures_spaceports(&self) -> bool {
+        false
+    }
+}
+
+/// Returns `true` if player can return to its spawning planet.
+fn spawning_planet_intact(world: &World, player_id: usize) -> bool {
+    if let Some(planet_id) = world.players[player_id].spawning_planet(world) {
+        !world.planets[planet_id].destroyed
+    } else {
+        false
+    }
+}
+
+/// Every player for themself, the last player in the game wins.
+pub struct DeathMatch;
+
+impl GameMode for DeathMatch {
+    /// Counts the players that are out of the game, while player is still in.
+    fn score(&self, world: &World, player_id: usize) -> u32 {
+        if world.players[player_id].out_of_game(world) {return 0};
+        world.players.iter().filter(|player| player.out_of_game(world)).count() as u32
+    }
+
+    fn can_respawn(&self, world: &World, player_id: usize) -> bool {
+        spawning_planet_intact(world, player_id)
+    }
+
+    fn winners(&self, world: &World) -> Vec<Winner> {
+        world.death_match_winner().map(Winner::Player).into_iter().collect()
+    }
+}
+
+/// Teams fight, the last allied teams in the game win.
+/// Players without a team fight for their species.
+pub struct TeamMatch;
+
+impl GameMode for TeamMatch {
+    /// Counts the enemies of player that are out of the game.
+    fn score(&self, world: &World, player_id: usize) -> u32 {
+        (0..world.players.len())
+            .filter(|&i| i != player_id &&
+                         !world.are_teammates(player_id, i) &&
+                         world.players[i].out_of_game(world))
+            .count() as u32
+    }
+
+    fn can_respawn(&self, world: &World, player_id: usize) -> bool {
+        spawning_planet_intact(world, player_id)
+    }
+
+    fn winners(&self, world: &World) -> Vec<Winner> {
+        let teams = world.winning_teams();
+        if !teams.is_empty() {
+            teams.into_iter().map(Winner::Team).collect()
+        } else if world.players.iter().all(|player| player.team.is_none()) {
+            world.team_match_winner().map(Winner::Species).into_iter().collect()
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// Players defend a planet's cities and spaceports against waves of invaders.
+/// The invaders win when the planet is destroyed or loses all its people.
+/// The defenders win when every wave has arrived and been killed.
+pub struct PlanetDefense {
+    pub planet: usize,
+}
+
+impl GameMode for PlanetDefense {
+    /// Counts the populated cities on the defended planet.
+    fn score(&self, world: &World, _player_id: usize) -> u32 {
+        let planet = &world.planets[self.planet];
+        if planet.destroyed {return 0};
+        planet.cities.iter()
+            .filter_map(|&city_id| city_id)
+            .filter(|&city_id| world.cities[city_id].population.iter().any(|&n| n > 0))
+            .count() as u32
+    }
+
+    fn can_respawn(&self, world: &World, _player_id: usize) -> bool {
+        !world.planets[self.planet].destroyed
+    }
+
+    fn winners(&self, world: &World) -> Vec<Winner> {
+        let planet = &world.planets[self.planet];
+        if planet.destroyed || planet.population(world) == 0 {
+            vec![Winner::Invaders]
+        } else if world.waves_defeated() {
+            vec![Winner::Defenders]
+        } else {
+            vec![]
+        }
+    }
+
+    fn defended_planet(&self) -> Option<usize> {
+        Some(self.planet)
+    }
+}
+
+/// Teams capture spaceports by holding them alone for a while.
+/// Controlled spaceports score over time and serve as respawn points.
+/// A team controlling every working spaceport wins.
+pub struct CaptureTheSpaceport;
+
+impl GameMode for CaptureTheSpaceport {
+    /// Returns the points scored by player's team holding spaceports.
+    fn score(&self, world: &World, player_id: usize) -> u32 {
+        match world.players[player_id].side() {
+            Some(Side::Team(team_id)) => world.teams[team_id].points,
+            _ => 0,
+        }
+    }
+
+    fn can_respawn(&self, world: &World, player_id: usize) -> bool {
+        spawning_planet_intact(world, player_id) ||
+        self.spawn_point(world, player_id).is_some()
+    }
+
+    /// Spawns at the first spaceport controlled by player's team.
+    fn spawn_point(&self, world: &World, player_id: usize) -> Option<(usize, u8)> {
+        let team_id = world.players[player_id].team?;
+        let &spaceport_id = world.controlled_spaceports(team_id).first()?;
+        world.spaceport_location(spaceport_id)
+    }
+
+    fn winners(&self, world: &World) -> Vec<Winner> {
+        let working: Vec<usize> = (0..world.spaceports.len())
+            .filter(|&i| !world.spaceports[i].destroyed)
+            .collect();
+        if working.is_empty() {return vec![]};
+        (0..world.teams.len())
+            .filter(|&team_id| world.controlled_spaceports(team_id).len() == working.len())
+            .map(Winner::Team)
+            .collect()
+    }
+
+    fn captures_spaceports(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,61 @@
+//! Ray intersection tests used for hit resolution.
+//!
+//! All functions expect the ray direction to be normalized
+//! and return the distance along the ray to the first hit.
+
+use vecmath::{vec3_dot, vec3_sub};
+
+/// Intersects ray with sphere.
+/// Returns `None` if the ray starts inside the sphere.
+pub fn ray_sphere(
+    origin: [f64; 3],
+    dir: [f64; 3],
+    center: [f64; 3],
+    radius: f64
+) -> Option<f64> {
+    let oc = vec3_sub(origin, center);
+    let c = vec3_dot(oc, oc) - radius * radius;
+    if c < 0.0 {return None};
+    let b = vec3_dot(oc, dir);
+    let h = b * b - c;
+    if h < 0.0 {return None};
+    let t = -b - h.sqrt();
+    if t >= 0.0 {Some(t)} else {None}
+}
+
+/// Intersects ray with capsule from `a` to `b`.
+pub fn ray_capsule(
+    origin: [f64; 3],
+    dir: [f64; 3],
+    a: [f64; 3],
+    b: [f64; 3],
+    radius: f64
+) -> Option<f64> {
+    let ba = vec3_sub(b, a);
+    let oa = vec3_sub(origin, a);
+    let baba = vec3_dot(ba, ba);
+    let bard = vec3_dot(ba, dir);
+    let baoa = vec3_dot(ba, oa);
+    let rdoa = vec3_dot(dir, oa);
+    let oaoa = vec3_dot(oa, oa);
+    let k = baba - bard * bard;
+    if k > 0.0 {
+        // Hit the cylinder body.
+        let l = baba * rdoa - baoa * bard;
+        let m = baba * oaoa - baoa * baoa - radius * radius * baba;
+        let h = l * l - k * m;
+        if h < 0.0 {return None};
+        let t = (-l - h.sqrt()) / k;
+        let y = baoa + t * bard;
+        if y > 0.0 && y < baba {
+            return if t >= 0.0 {Some(t)} else {None};
+        }
+    }
+    // Hit one of the end caps.
+    let t1 = ray_sphere(origin, dir, a, radius);
+    let t2 = ray_sphere(origin, dir, b, radius);
+    match (t1, t2) {
+        (Some(t1), Some(t2)) => Some(t1.min(t2)),
+        (t1, t2) => t1.or(t2),
+    }
+}
@@ -6,11 +6,21 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
         !filter_cache.contains(new_expr)
     };
 
-    let ref mut world = World::new();
-    let ref mut state = State::new();
+    let world = &mut World::new();
+    let state = &mut State::new();
 
     // Execute expressions on world.
     for expr in story {
+        // Check that the match phase allows the action.
+        let allowed = match *expr {
+            ShootAtPlanet(..) | ShootAtPlayer(..) | ShootAtNothing(..) | ShootAtInvader(..) |
+            Fire(..) | FireCanon(..) | Heal(..) | Kill(..) => world.combat_allowed(),
+            Spawn(..) => world.spawn_allowed(),
+            SelectGameMode(..) | SelectLoadout(..) | AssignTeam(..) => world.lobby_open(),
+            _ => true,
+        };
+        if !allowed {return None};
+
         if let CreatePlanet(name) = *expr {
             state.create_planet(name, world);
         }
@@ -28,7 +38,7 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
         }
 
         if let CreateSpaceport(planet, location) = *expr {
-            if !state.create_spaceport(planet, location, world).is_ok() {
+            if state.create_spaceport(planet, location, world).is_err() {
                 return None;
             }
         }
@@ -49,74 +59,132 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
             state.create_canon(name, world);
         }
 
+        if let AddWave(delay_milliseconds, ships, boarders) = *expr {
+            world.waves.push(Wave {delay_milliseconds, ships, boarders});
+        }
+
+        if let ShootAtInvader(shooter, hand, planet, location) = *expr {
+            if let (Some(shooter_id), Some(planet_id)) =
+                (*state.player_mut(shooter), *state.planet_mut(planet))
+            {
+                if world.shoot_at_invader(shooter_id, hand, planet_id, location as u8).is_err() {
+                    return None;
+                }
+            }
+        }
+
+        if let SetCaptureMilliseconds(ms) = *expr {
+            world.capture_milliseconds = ms;
+        }
+
+        if let SelectGameMode(name) = *expr {
+            if let Some(game_mode) = state.game_mode(name) {
+                world.game_mode = game_mode;
+            } else {
+                return None;
+            }
+        }
+
+        if let CreateTeam(name) = *expr {
+            state.create_team(name, world);
+        }
+
+        if let AssignTeam(player, team) = *expr {
+            if state.assign_team(player, team, world).is_err() {
+                return None;
+            }
+        }
+
+        if let AllyTeams(a, b) = *expr {
+            if state.ally_teams(a, b, world).is_err() {
+                return None;
+            }
+        }
+
+        if let CreateLoadout(name) = *expr {
+            state.create_loadout(name, world);
+        }
+
         if let AssignOrbit(name, orbit) = *expr {
-            if !state.assign_orbit(name, orbit, world).is_ok() {
+            if state.assign_orbit(name, orbit, world).is_err() {
                 return None;
             }
         }
 
         if let AssignHomePlanet(species, planet) = *expr {
-            if !state.assign_home_planet(species, planet, world).is_ok() {
+            if state.assign_home_planet(species, planet, world).is_err() {
                 return None;
             }
         }
 
         if let AssignLocation(city, planet, location) = *expr {
-            if !state.assign_location(city, planet, location, world).is_ok() {
+            if state.assign_location(city, planet, location, world).is_err() {
                 return None;
             }
         }
 
         if let AssignWeapon(player, weapon, hand) = *expr {
-            if !state.assign_weapon(player, weapon, hand, world).is_ok() {
+            if state.assign_weapon(player, weapon, hand, world).is_err() {
                 return None;
             }
         }
 
         if let AssignCanon(spaceship, canon, canon_slot) = *expr {
-            if !state.assign_canon(spaceship, canon, canon_slot, world).is_ok() {
+            if state.assign_canon(spaceship, canon, canon_slot, world).is_err() {
                 return None;
             }
         }
 
         if let AssignSpecies(player, species) = *expr {
-            if !state.assign_species(player, species, world).is_ok() {
+            if state.assign_species(player, species, world).is_err() {
                 return None;
             }
         }
 
         if let DestroySpaceport(planet, location) = *expr {
-            if !state.destroy_spaceport(planet, location, world).is_ok() {
+            if state.destroy_spaceport(planet, location, world).is_err() {
                 return None;
             }
         }
 
         if let DestroyPlanet(planet) = *expr {
-            if !state.destroy_planet(planet, world).is_ok() {
+            if state.destroy_planet(planet, world).is_err() {
                 return None;
             }
         }
 
         if let RebuildSpaceport(planet, location) = *expr {
-            if !state.rebuild_spaceport(planet, location, world).is_ok() {
+            if state.rebuild_spaceport(planet, location, world).is_err() {
                 return None;
             }
         }
 
         if let PopulateCity(name, n, species) = *expr {
-            if !state.populate_city(name, n, species, world).is_ok() {
+            if state.populate_city(name, n, species, world).is_err() {
+                return None;
+            }
+        }
+
+        if let SetSpeciesGrowth(species, growth_per_mille) = *expr {
+            if let Some(species_id) = *state.species_mut(species) {
+                world.species[species_id].growth_per_mille = growth_per_mille;
+            } else {
                 return None;
             }
         }
 
+        if let SetMigrationRate(per_mille) = *expr {
+            world.migration_per_mille = per_mille.min(1000);
+        }
+
         if let DropWeapon(player, hand) = *expr {
-            if !state.drop_weapon(player, hand, world).is_ok() {
+            if state.drop_weapon(player, hand, world).is_err() {
                 return None;
             }
         }
 
         if let Kill(player) = *expr {
-            if !state.kill(player, world).is_ok() {
+            if state.kill(player, world).is_err() {
                 return None;
             }
         }
@@ -145,16 +213,274 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
             }
         }
 
+        if let SetWeaponProjectileSpeed(weapon, speed) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].projectile_speed =
+                    if speed == 0 {None} else {Some(speed as f64)};
+            }
+        }
+
+        if let SetWeaponProjectileLifetime(weapon, lifetime_milliseconds) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].projectile_lifetime_milliseconds = lifetime_milliseconds;
+            }
+        }
+
+        if let SetWeaponSpread(weapon, spread_milliradians) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].spread_milliradians = spread_milliradians;
+            }
+        }
+
+        if let SetWeaponAccuracy(weapon, accuracy_percent) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].accuracy_percent = accuracy_percent;
+            }
+        }
+
+        if let SetWeaponDamageVariance(weapon, damage_variance) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].damage_variance = damage_variance;
+            }
+        }
+
+        if let SetSeed(seed) = *expr {
+            world.rng = Rng::new(seed);
+        }
+
+        if let SetWeaponDamageType(weapon, damage_type) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].damage_type = damage_type;
+            }
+        }
+
+        if let SetShield(player, shield) = *expr {
+            if let Some(player_id) = *state.player_mut(player) {
+                world.players[player_id].shield = shield;
+                world.players[player_id].max_shield = shield;
+            }
+        }
+
+        if let SetShieldResistance(player, damage_type, percent) = *expr {
+            if let Some(player_id) = *state.player_mut(player) {
+                world.players[player_id].shield_resistance_percent[damage_type as usize] = percent;
+            }
+        }
+
+        if let SetArmor(player, armor) = *expr {
+            if let Some(player_id) = *state.player_mut(player) {
+                world.players[player_id].armor = armor;
+            }
+        }
+
+        if let SetArmorResistance(player, damage_type, percent) = *expr {
+            if let Some(player_id) = *state.player_mut(player) {
+                world.players[player_id].armor_resistance_percent[damage_type as usize] = percent;
+            }
+        }
+
+        if let SetMaxLife(life) = *expr {
+            world.max_life = life;
+        }
+
+        if let SetWeaponHealing(weapon, healing) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].healing = healing;
+            }
+        }
+
+        if let StockMedkits(city, n) = *expr {
+            if let Some(city_id) = *state.city_mut(city) {
+                world.cities[city_id].medkits = n;
+            }
+        }
+
+        if let PickUpMedkit(player, city) = *expr {
+            if state.pick_up_medkit(player, city, world).is_err() {
+                return None;
+            }
+        }
+
+        if let Heal(healer, hand, target) = *expr {
+            if state.heal(healer, hand, target, world).is_err() {
+                return None;
+            }
+        }
+
+        if let SetWeaponStatusEffect(weapon, effect, milliseconds) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].status_effect = Some((effect, milliseconds));
+            }
+        }
+
+        if let SetFriendlyFire(rule) = *expr {
+            world.friendly_fire = rule;
+        }
+
+        if let SetSelfDamage(value) = *expr {
+            world.self_damage = value;
+        }
+
+        if let SetWeaponBlastRadius(weapon, radius) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].blast_radius = radius as f64;
+            }
+        }
+
+        if let SetCanonBlastRadius(canon, radius) = *expr {
+            if let Some(canon_id) = *state.canon_mut(canon) {
+                world.canons[canon_id].blast_radius = radius as f64;
+            }
+        }
+
+        if let FireCanon(player, canon_slot, planet, location) = *expr {
+            if state.fire_canon(player, canon_slot, planet, location, world).is_err() {
+                return None;
+            }
+        }
+
+        if let SetWeaponChargeMilliseconds(weapon, ms) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].charge_milliseconds = ms;
+            }
+        }
+
+        if let SetPlanetDestroyerLimit(limit) = *expr {
+            world.planet_destroyer_limit = Some(limit);
+        }
+
+        if let RequireSpaceshipForPlanetDestroyer(value) = *expr {
+            world.planet_destroyer_requires_spaceship = value;
+        }
+
+        if let SetWeaponMagazine(weapon, rounds) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].magazine = Some(rounds);
+            }
+        }
+
+        if let SetWeaponReloadMilliseconds(weapon, ms) = *expr {
+            if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                world.weapons[weapon_id].reload_milliseconds = ms;
+            }
+        }
+
+        if let SetSpareAmmo(player, rounds) = *expr {
+            if let Some(player_id) = *state.player_mut(player) {
+                world.players[player_id].spare_ammo = rounds;
+            }
+        }
+
+        if let SetLoadoutWeapon(loadout, weapon, hand) = *expr {
+            if state.set_loadout_weapon(loadout, weapon, hand, world).is_err() {
+                return None;
+            }
+        }
+
+        if let SetLoadoutHolster(loadout, weapon, slot) = *expr {
+            if state.set_loadout_holster(loadout, weapon, slot, world).is_err() {
+                return None;
+            }
+        }
+
+        if let RestrictLoadout(loadout, species) = *expr {
+            if state.restrict_loadout(loadout, species, world).is_err() {
+                return None;
+            }
+        }
+
+        if let SelectLoadout(player, loadout) = *expr {
+            if state.select_loadout(player, loadout, world).is_err() {
+                return None;
+            }
+        }
+
+        if let SetHolsterSlots(player, slots) = *expr {
+            if let Some(player_id) = *state.player_mut(player) {
+                world.set_holster_slots(player_id, slots);
+            }
+        }
+
+        if let AssignHolster(player, weapon, slot) = *expr {
+            if state.assign_holster(player, weapon, slot, world).is_err() {
+                return None;
+            }
+        }
+
+        if let Holster(player, hand, slot) = *expr {
+            if state.holster(player, hand, slot, world).is_err() {
+                return None;
+            }
+        }
+
+        if let Draw(player, hand, slot) = *expr {
+            if state.draw(player, hand, slot, world).is_err() {
+                return None;
+            }
+        }
+
+        if let PickUpWeapon(player, hand) = *expr {
+            if state.pick_up_weapon(player, hand, world).is_err() {
+                return None;
+            }
+        }
+
+        if let Reload(player, hand) = *expr {
+            if state.reload(player, hand, world).is_err() {
+                return None;
+            }
+        }
+
+        if let StockAmmo(planet, location, n) = *expr {
+            if state.stock_ammo(planet, location, n, world).is_err() {
+                return None;
+            }
+        }
+
+        if let PickUpAmmo(player, planet, location) = *expr {
+            if state.pick_up_ammo(player, planet, location, world).is_err() {
+                return None;
+            }
+        }
+
+        if let SetHitZoneDamage(zone, percent) = *expr {
+            *world.hit_zone_damage_percent_mut(zone) = percent;
+        }
+
         if let SetCanonFirepower(canon, firepower) = *expr {
             if let Some(canon_id) = *state.canon_mut(canon) {
                 world.canons[canon_id].firepower = firepower;
             }
         }
 
+        if let SetPlanetPosition(planet, pos) = *expr {
+            if let Some(planet_id) = *state.planet_mut(planet) {
+                world.planets[planet_id].pos = [
+                    pos[0] as f64 * 1000.0,
+                    pos[1] as f64 * 1000.0,
+                    pos[2] as f64 * 1000.0,
+                ];
+            }
+        }
+
+        if let SetPlanetRadius(planet, radius) = *expr {
+            if let Some(planet_id) = *state.planet_mut(planet) {
+                world.planets[planet_id].radius = radius as f64 * 1000.0;
+            }
+        }
+
+        if let SetSpaceshipPosition(spaceship, pos) = *expr {
+            if let Some(spaceship_id) = *state.spaceship_mut(spaceship) {
+                world.spaceships[spaceship_id].pos = [pos[0] as f64, pos[1] as f64, pos[2] as f64];
+            }
+        }
+
         if let ShootAtPlanet(player, hand, planet) = *expr {
             if let Some(player_id) = *state.player_mut(player) {
                 if let Some(planet_id) = *state.planet_mut(planet) {
-                    world.shoot_at_planet(player_id, hand, planet_id);
+                    if world.shoot_at_planet(player_id, hand, planet_id).is_err() {
+                        return None;
+                    }
                 }
             }
         }
@@ -173,6 +499,12 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
             }
         }
 
+        if let Fire(player, hand) = *expr {
+            if let Some(player_id) = *state.player_mut(player) {
+                world.fire(player_id, hand);
+            }
+        }
+
         if let RechargeMilliseconds(player, hand, recharge_milliseconds) = *expr {
             if let Some(player_id) = *state.player_mut(player) {
                 world.recharge_milliseconds(player_id, hand, recharge_milliseconds);
@@ -183,24 +515,72 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
             world.recharge_milliseconds_all_weapons(recharge_milliseconds);
         }
 
+        if let UpdateMilliseconds(milliseconds) = *expr {
+            world.update_milliseconds(milliseconds);
+        }
+
         if let Spawn(player) = *expr {
             if let Some(player_id) = *state.player_mut(player) {
-                world.spawn(player_id);
+                if world.spawn(player_id).is_err() {
+                    return None;
+                }
+            }
+        }
+
+        if let BoardSpaceship(player, spaceship) = *expr {
+            if state.board_spaceship(player, spaceship, world).is_err() {
+                return None;
+            }
+        }
+
+        if let UpdatePose(player, tracker, pos, orientation) = *expr {
+            if let Some(player_id) = *state.player_mut(player) {
+                world.update_pose(player_id, tracker, Pose::from_tracking(pos, orientation));
+            }
+        }
+
+        if let OpenLobby = *expr {
+            if world.open_lobby().is_err() {
+                return None;
+            }
+        }
+
+        if let StartMatch = *expr {
+            if world.start_match().is_err() {
+                return None;
+            }
+        }
+
+        if let SetPhaseDuration(phase, ms) = *expr {
+            if let Some(duration) = world.phase_duration_mut(phase) {
+                *duration = ms;
+            } else {
+                return None;
             }
         }
+
+        world.end_match_if_decided();
+    }
+
+    if let Some(phase) = world.phase {
+        let new_expr = MatchPhase(phase);
+        if can_add(&new_expr) {return Some(new_expr)};
+
+        let new_expr = PhaseMillisecondsLeft(world.phase_milliseconds);
+        if can_add(&new_expr) {return Some(new_expr)};
     }
 
-    if world.planets.len() > 0 {
+    if !world.planets.is_empty() {
         let new_expr = ContainsPlanets;
         if can_add(&new_expr) {return Some(new_expr)};
     }
 
-    if world.weapons.len() > 0 {
+    if !world.weapons.is_empty() {
         let new_expr = ContainsWeapons;
         if can_add(&new_expr) {return Some(new_expr)};
     }
 
-    if world.players.len() > 0 {
+    if !world.players.is_empty() {
         let new_expr = ContainsPlayers;
         if can_add(&new_expr) {return Some(new_expr)};
     }
@@ -208,11 +588,49 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
     let new_expr = NumberOfPlayersLeft(world.number_of_players_left());
     if can_add(&new_expr) {return Some(new_expr)};
 
+    let new_expr = NumberOfProjectiles(world.projectiles.len());
+    if can_add(&new_expr) {return Some(new_expr)};
+
+    for &name in SpaceshipName::all() {
+        if let Some(spaceship_id) = *state.spaceship_mut(name) {
+            let new_expr = HasHull(name, world.spaceships[spaceship_id].hull);
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let new_expr = IsSpaceshipDestroyed(name, world.spaceships[spaceship_id].destroyed);
+            if can_add(&new_expr) {return Some(new_expr)};
+        }
+    }
+
     for &name in PlanetName::all() {
         if let Some(planet_id) = *state.planet_mut(name) {
             let new_expr = ContainsPlanet(name);
             if can_add(&new_expr) {return Some(new_expr)};
 
+            for &location in LocationName::all() {
+                if world.game_mode.defended_planet() == Some(planet_id) {
+                    let n = world.invaders.iter()
+                        .filter(|invader| invader.planet == planet_id &&
+                                          invader.location == location as u8)
+                        .count();
+                    let new_expr = InvadersAt(name, location, n);
+                    if can_add(&new_expr) {return Some(new_expr)};
+                }
+
+                if let Some(spaceport_id) = world.planets[planet_id].spaceports[location as usize] {
+                    let new_expr = SpaceportHull(name, location, world.spaceports[spaceport_id].hull);
+                    if can_add(&new_expr) {return Some(new_expr)};
+
+                    if let Some(team_id) = world.spaceport_controller(spaceport_id) {
+                        for &team in TeamName::all() {
+                            if *state.team_mut(team) == Some(team_id) {
+                                let new_expr = SpaceportController(name, location, team);
+                                if can_add(&new_expr) {return Some(new_expr)};
+                            }
+                        }
+                    }
+                }
+            }
+
             if let Some(orbit_id) = world.planets[planet_id].orbit {
                 for &orbit in OrbitName::all() {
                     if let Some(id) = *state.orbit_mut(orbit) {
@@ -236,6 +654,14 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
             let new_expr = PlanetHasNumberOfPeople(name, population);
             if can_add(&new_expr) {return Some(new_expr)};
 
+            for &species in SpeciesName::all() {
+                if let Some(species_id) = *state.species_mut(species) {
+                    let n = world.planets[planet_id].casualties[species_id];
+                    let new_expr = Casualties(name, species, n);
+                    if can_add(&new_expr) {return Some(new_expr)};
+                }
+            }
+
             let new_expr = IsPlanetDestroyed(name, world.planets[planet_id].destroyed);
             if can_add(&new_expr) {return Some(new_expr)};
         }
@@ -254,9 +680,31 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
                 }
             }
 
-            if let Some(id) = world.team_match_winner() {
-                if id == species_id {
-                    let new_expr = TeamMatchWinner(species);
+        }
+    }
+
+    for winner in world.game_mode.winners(world) {
+        if let Some(winner) = state.winner_name(winner) {
+            let new_expr = MatchWinner(winner);
+            if can_add(&new_expr) {return Some(new_expr)};
+        }
+    }
+
+    for &team in TeamName::all() {
+        if let Some(team_id) = *state.team_mut(team) {
+            for &ally in TeamName::all() {
+                if let Some(ally_id) = *state.team_mut(ally) {
+                    if world.teams[team_id].allies.contains(&ally_id) {
+                        let new_expr = AreAllies(team, ally);
+                        if can_add(&new_expr) {return Some(new_expr)};
+                    }
+                }
+            }
+
+            for &planet in PlanetName::all() {
+                if let Some(planet_id) = *state.planet_mut(planet) {
+                    let new_expr = TeamHasSpaceTravel(team, planet,
+                        world.team_has_space_travel(team_id, planet_id));
                     if can_add(&new_expr) {return Some(new_expr)};
                 }
             }
@@ -272,6 +720,14 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
                 if can_add(&new_expr) {return Some(new_expr)};
             }
 
+            let new_expr = HasMedkits(city, world.cities[city_id].medkits);
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let population = world.cities[city_id].population.iter()
+                .fold(0, |sum: u64, &n| sum.saturating_add(n));
+            let new_expr = CityHasNumberOfPeople(city, population);
+            if can_add(&new_expr) {return Some(new_expr)};
+
             if world.city_spaceport(city_id).is_some() {
                 let new_expr = CityHasSpaceport(city);
                 if can_add(&new_expr) {return Some(new_expr)};
@@ -279,7 +735,6 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
         }
     }
 
-    let death_match_winner = world.death_match_winner();
     for &player in PlayerName::all() {
         if let Some(player_id) = *state.player_mut(player) {
             if world.players[player_id].left_weapon.is_none() {
@@ -293,25 +748,43 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
 
             for &weapon in WeaponName::all() {
                 if let Some(id) = *state.weapon_mut(weapon) {
-                    if let Some(weapon_id) = world.players[player_id].left_weapon {
-                        if id == weapon_id {
+                    for item_id in world.players[player_id].carried_weapons() {
+                        if id == world.weapon_items[item_id].weapon {
                             let new_expr = HasWeapon(player, weapon);
                             if can_add(&new_expr) {return Some(new_expr)};
                         }
                     }
 
-                    if let Some(weapon_id) = world.players[player_id].right_weapon {
-                        if id == weapon_id {
-                            let new_expr = HasWeapon(player, weapon);
+                    for (slot, item_id) in world.players[player_id].holsters.iter().enumerate() {
+                        if item_id.map(|item_id| world.weapon_items[item_id].weapon) == Some(id) {
+                            let new_expr = Holstered(player, slot as u8, weapon);
                             if can_add(&new_expr) {return Some(new_expr)};
                         }
-                     }
+                    }
                 }
             }
 
             let new_expr = HasWeapons(player, world.players[player_id].has_weapons());
             if can_add(&new_expr) {return Some(new_expr)};
 
+            if let Some(team_id) = world.players[player_id].team {
+                for &team in TeamName::all() {
+                    if *state.team_mut(team) == Some(team_id) {
+                        let new_expr = IsOnTeam(player, team);
+                        if can_add(&new_expr) {return Some(new_expr)};
+                    }
+                }
+            }
+
+            if let Some(loadout_id) = world.players[player_id].loadout {
+                for &loadout in LoadoutName::all() {
+                    if *state.loadout_mut(loadout) == Some(loadout_id) {
+                        let new_expr = HasLoadout(player, loadout);
+                        if can_add(&new_expr) {return Some(new_expr)};
+                    }
+                }
+            }
+
             if let Some(planet_id) = world.players[player_id].spawning_planet(world) {
                 for &planet in PlanetName::all() {
                     if let Some(id) = *state.planet_mut(planet) {
@@ -327,16 +800,106 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
             let new_expr = OutOfGame(player, out_of_game);
             if can_add(&new_expr) {return Some(new_expr)};
 
-            if let Some(id) = death_match_winner {
-                if id == player_id {
-                    let new_expr = DeathMatchWinner(player);
-                    if can_add(&new_expr) {return Some(new_expr)};
+            let new_expr = Score(player, world.game_mode.score(world, player_id));
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let stats = &world.players[player_id].stats;
+            let new_expr = KillCount(player, stats.kills);
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let new_expr = DeathCount(player, stats.deaths);
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let new_expr = ShotsFired(player, stats.shots_fired);
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let new_expr = AccuracyPercent(player, stats.accuracy_percent());
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let new_expr = PlanetsDestroyed(player, stats.planets_destroyed);
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let new_expr = AssistCount(player, stats.assists);
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let new_expr = PeopleKilled(player, stats.people_killed());
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            for &planet in PlanetName::all() {
+                for &species in SpeciesName::all() {
+                    if let (Some(planet_id), Some(species_id)) =
+                        (*state.planet_mut(planet), *state.species_mut(species))
+                    {
+                        if let Some(&n) = stats.casualties.get(&(planet_id, species_id)) {
+                            let new_expr = CasualtiesBy(player, planet, species, n);
+                            if can_add(&new_expr) {return Some(new_expr)};
+                        }
+                    }
+                }
+            }
+
+            let new_expr = WarCrimeScore(player, stats.war_crime_score());
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            for &weapon in WeaponName::all() {
+                if let Some(weapon_id) = *state.weapon_mut(weapon) {
+                    if let Some(&damage) = stats.damage.get(&Cause::Weapon(weapon_id)) {
+                        let new_expr = WeaponDamage(player, weapon, damage);
+                        if can_add(&new_expr) {return Some(new_expr)};
+                    }
+                }
+            }
+
+            if let Some(ref death) = world.players[player_id].death {
+                if let Some(attack) = death.attack {
+                    if let Some(killer) = state.player_name(attack.attacker) {
+                        let new_expr = match attack.cause {
+                            Cause::Weapon(id) => state.weapon_name(id)
+                                .map(|weapon| KilledBy(player, killer, weapon)),
+                            Cause::Canon(id) => state.canon_name(id)
+                                .map(|canon| KilledByCanon(player, killer, canon)),
+                        };
+                        if let Some(new_expr) = new_expr {
+                            if can_add(&new_expr) {return Some(new_expr)};
+                        }
+                    }
+
+                    if let Some(hand) = attack.hand {
+                        let new_expr = KilledWithHand(player, hand);
+                        if can_add(&new_expr) {return Some(new_expr)};
+                    }
+                }
+
+                for &assister_id in &death.assists {
+                    if let Some(assister) = state.player_name(assister_id) {
+                        let new_expr = Assisted(assister, player);
+                        if can_add(&new_expr) {return Some(new_expr)};
+                    }
                 }
             }
 
+            if let Some(rank) = scoreboard(world).iter().position(|row| row.player == player_id) {
+                let new_expr = ScoreboardRank(player, rank + 1);
+                if can_add(&new_expr) {return Some(new_expr)};
+            }
+
             let new_expr = HasLife(player, world.players[player_id].life);
             if can_add(&new_expr) {return Some(new_expr)};
 
+            let new_expr = HasShield(player, world.players[player_id].shield);
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            let new_expr = HasArmor(player, world.players[player_id].armor);
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            for &effect in StatusEffect::all() {
+                let ms = world.players[player_id].status_milliseconds[effect as usize];
+                if ms > 0 {
+                    let new_expr = HasStatus(player, effect, ms);
+                    if can_add(&new_expr) {return Some(new_expr)};
+                }
+            }
+
             let new_expr = IsDead(player, world.players[player_id].dead);
             if can_add(&new_expr) {return Some(new_expr)};
 
@@ -344,14 +907,24 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
             let new_expr = MillisecondsToRecharge(player, Hand::Left, left_recharge_ms);
             if can_add(&new_expr) {return Some(new_expr)};
 
-            let new_expr = CanShoot(player, Hand::Left, left_recharge_ms == 0);
+            let new_expr = CanShoot(player, Hand::Left, world.can_shoot(player_id, Hand::Left));
             if can_add(&new_expr) {return Some(new_expr)};
 
             let right_recharge_ms = world.players[player_id].right_recharge_milliseconds;
             let new_expr = MillisecondsToRecharge(player, Hand::Right, right_recharge_ms);
             if can_add(&new_expr) {return Some(new_expr)};
 
-            let new_expr = CanShoot(player, Hand::Right, right_recharge_ms == 0);
+            let new_expr = CanShoot(player, Hand::Right, world.can_shoot(player_id, Hand::Right));
+            if can_add(&new_expr) {return Some(new_expr)};
+
+            for &hand in &[Hand::Left, Hand::Right] {
+                if let Some(n) = world.ammo_left(player_id, hand) {
+                    let new_expr = AmmoLeft(player, hand, n);
+                    if can_add(&new_expr) {return Some(new_expr)};
+                }
+            }
+
+            let new_expr = SpareAmmo(player, world.players[player_id].spare_ammo);
             if can_add(&new_expr) {return Some(new_expr)};
 
             if let Some(planet_id) = world.players[player_id].on_planet {
@@ -360,10 +933,58 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
                         if id == planet_id {
                             let new_expr = IsOnPlanet(player, planet);
                             if can_add(&new_expr) {return Some(new_expr)};
+
+                            for &location in LocationName::all() {
+                                if world.is_at_location(player_id, planet_id, location as u8) {
+                                    let new_expr = IsAtLocation(player, planet, location);
+                                    if can_add(&new_expr) {return Some(new_expr)};
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(spaceship_id) = world.players[player_id].on_spaceship {
+                for &spaceship in SpaceshipName::all() {
+                    if let Some(id) = *state.spaceship_mut(spaceship) {
+                        if id == spaceship_id {
+                            let new_expr = IsOnSpaceship(player, spaceship);
+                            if can_add(&new_expr) {return Some(new_expr)};
                         }
                     }
                 }
             }
+
+            for &hand in &[Hand::Left, Hand::Right] {
+                if let Some(target) = *world.players[player_id].last_hit_mut(hand) {
+                    if let Some(target) = state.target_name(target, world) {
+                        let new_expr = LastHit(player, hand, target);
+                        if can_add(&new_expr) {return Some(new_expr)};
+                    }
+                }
+            }
+
+            if let Some(zone) = world.players[player_id].last_hit_zone {
+                let new_expr = LastHitZone(player, zone);
+                if can_add(&new_expr) {return Some(new_expr)};
+            }
+
+            for &tracker in Tracker::all() {
+                let (pos, orientation) = world.players[player_id].pose(tracker).to_tracking();
+                let new_expr = HasPose(player, tracker, pos, orientation);
+                if can_add(&new_expr) {return Some(new_expr)};
+            }
+
+            for &other in PlayerName::all() {
+                if other == player {continue};
+                if let Some(other_id) = *state.player_mut(other) {
+                    if let Some(dist) = world.player_distance(player_id, other_id) {
+                        let new_expr = DistanceBetween(player, other, dist.round() as u32);
+                        if can_add(&new_expr) {return Some(new_expr)};
+                    }
+                }
+            }
         }
     }
 
@@ -371,6 +992,70 @@ pub fn infer(cache: &HashSet<Expr>, filter_cache: &HashSet<Expr>, story: &[Expr]
         if let Some(weapon_id) = *state.weapon_mut(weapon) {
             let new_expr = NumberOfWeaponUsers(weapon, world.number_of_weapon_users(weapon_id));
             if can_add(&new_expr) {return Some(new_expr)};
+
+            for item in &world.weapon_items {
+                if item.weapon != weapon_id {continue};
+                if let Some(lying) = item.lying {
+                    for &planet in PlanetName::all() {
+                        if *state.planet_mut(planet) == Some(lying.planet) {
+                            let new_expr = WeaponLyingOn(weapon, planet);
+                            if can_add(&new_expr) {return Some(new_expr)};
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for charge in &world.charges {
+        if let (Some(TargetName::Player(player)), Some(TargetName::Planet(planet))) =
+            (state.target_name(Target::Player(charge.shooter), world),
+             state.target_name(Target::Planet(charge.planet), world))
+        {
+            let new_expr = Charging(player, planet, charge.milliseconds_left);
+            if can_add(&new_expr) {return Some(new_expr)};
+        }
+    }
+
+    for event in &world.events {
+        match *event {
+            Event::PlanetDestroyerCharging {shooter, planet} => {
+                if let (Some(TargetName::Player(player)), Some(TargetName::Planet(planet))) =
+                    (state.target_name(Target::Player(shooter), world),
+                     state.target_name(Target::Planet(planet), world))
+                {
+                    let new_expr = PlanetDestroyerAnnounced(player, planet);
+                    if can_add(&new_expr) {return Some(new_expr)};
+                }
+            }
+            Event::BlastDamage {target, damage} => {
+                if let Some(target) = state.target_name(target, world) {
+                    let new_expr = BlastHit(target, damage);
+                    if can_add(&new_expr) {return Some(new_expr)};
+                }
+            }
+            Event::PlanetDestroyerInterrupted {shooter, planet} => {
+                if let (Some(TargetName::Player(player)), Some(TargetName::Planet(planet))) =
+                    (state.target_name(Target::Player(shooter), world),
+                     state.target_name(Target::Planet(planet), world))
+                {
+                    let new_expr = PlanetDestroyerInterrupted(player, planet);
+                    if can_add(&new_expr) {return Some(new_expr)};
+                }
+            }
+        }
+    }
+
+    let new_expr = NumberOfPlanetDestroyers(world.number_of_planet_destroyers());
+    if can_add(&new_expr) {return Some(new_expr)};
+
+    let new_expr = WavesArrived(world.waves_arrived);
+    if can_add(&new_expr) {return Some(new_expr)};
+
+    for &loadout in LoadoutName::all() {
+        if let Some(loadout_id) = *state.loadout_mut(loadout) {
+            let new_expr = LoadoutValid(loadout, world.loadout_valid(loadout_id));
+            if can_add(&new_expr) {return Some(new_expr)};
         }
     }
 
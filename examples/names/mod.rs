@@ -13,7 +13,7 @@ pub enum PlanetName {
 
 impl PlanetName {
     pub fn all() -> &'static [PlanetName] {
-        return &[
+        &[
             Tellar,
             Munos,
             Sand,
@@ -38,7 +38,7 @@ pub enum OrbitName {
 
 impl OrbitName {
     pub fn all() -> &'static [OrbitName] {
-        return &[
+        &[
             OrbitName::B,
             OrbitName::C,
             OrbitName::D,
@@ -59,7 +59,7 @@ pub enum SpeciesName {
 
 impl SpeciesName {
     pub fn all() -> &'static [SpeciesName] {
-        return &[
+        &[
             Vatrax,
             Ralm,
             Protrak,
@@ -163,3 +163,63 @@ impl CanonName {
         ]
     }
 }
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum LoadoutName {
+    Assault,
+    Sniper,
+}
+
+impl LoadoutName {
+    pub fn all() -> &'static [LoadoutName] {
+        &[
+            Assault,
+            Sniper,
+        ]
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TeamName {
+    Red,
+    Blue,
+    Green,
+}
+
+impl TeamName {
+    pub fn all() -> &'static [TeamName] {
+        &[
+            Red,
+            Blue,
+            Green,
+        ]
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum GameModeName {
+    DeathMatch,
+    TeamMatch,
+    /// Defend a planet.
+    PlanetDefense(PlanetName),
+    CaptureTheSpaceport,
+}
+
+/// The winner of a match.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum WinnerName {
+    Player(PlayerName),
+    Team(TeamName),
+    Species(SpeciesName),
+    Invaders,
+    Defenders,
+}
+
+/// Something that can be hit.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TargetName {
+    Player(PlayerName),
+    Spaceship(SpaceshipName),
+    Planet(PlanetName),
+    Spaceport(PlanetName, LocationName),
+}
@@ -0,0 +1,37 @@
+//! Deterministic random number generator.
+//!
+//! The whole generator state is a single `u64`,
+//! so it can be stored and restored to replay a match.
+
+/// The seed used by new worlds.
+pub const DEFAULT_SEED: u64 = 0x5EED_7A99_1570_0000;
+
+/// A SplitMix64 random number generator.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Rng {
+    pub state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng {state: seed}
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in the range `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a number in the range `[-n, n]`.
+    pub fn next_offset(&mut self, n: u16) -> i32 {
+        (self.next_u64() % (2 * n as u64 + 1)) as i32 - n as i32
+    }
+}
@@ -55,6 +55,16 @@ pub struct State {
     folkum: Option<usize>,
     /// Reference to the SR6 canon.
     sr6: Option<usize>,
+    /// Reference to the Assault loadout.
+    assault: Option<usize>,
+    /// Reference to the Sniper loadout.
+    sniper: Option<usize>,
+    /// Reference to the Red team.
+    red: Option<usize>,
+    /// Reference to the Blue team.
+    blue: Option<usize>,
+    /// Reference to the Green team.
+    green: Option<usize>,
 }
 
 impl State {
@@ -87,6 +97,11 @@ impl State {
             carl: None,
             folkum: None,
             sr6: None,
+            assault: None,
+            sniper: None,
+            red: None,
+            blue: None,
+            green: None,
         }
     }
 
@@ -182,7 +197,7 @@ impl State {
     ) -> Result<(), ()> {
         let city_id = self.city_mut(city).ok_or(())?;
         let planet_id = self.planet_mut(planet).ok_or(())?;
-        let ref mut city = world.cities[city_id];
+        let city = &mut world.cities[city_id];
         city.planet = Some(planet_id);
         city.location = Some(location as u8);
         world.planets[planet_id].cities[location as usize] = Some(city_id);
@@ -209,7 +224,7 @@ impl State {
     ) -> Result<(), ()> {
         let planet_id = self.planet_mut(planet).ok_or(())?;
         let spaceport_id = world.planets[planet_id].spaceports[location as usize].ok_or(())?;
-        world.spaceports[spaceport_id].destroyed = true;
+        world.destroy_spaceport(spaceport_id);
         Ok(())
     }
 
@@ -221,7 +236,7 @@ impl State {
     ) -> Result<(), ()> {
         let planet_id = self.planet_mut(planet).ok_or(())?;
         let spaceport_id = world.planets[planet_id].spaceports[location as usize].ok_or(())?;
-        world.spaceports[spaceport_id].destroyed = false;
+        world.rebuild_spaceport(spaceport_id);
         Ok(())
     }
 
@@ -234,7 +249,7 @@ impl State {
     ) -> Result<(), ()> {
         let city_id = self.city_mut(city).ok_or(())?;
         let species_id = self.species_mut(species).ok_or(())?;
-        world.cities[city_id].population[species_id as usize] = n;
+        world.cities[city_id].population[species_id] = n;
         Ok(())
     }
 
@@ -273,7 +288,85 @@ impl State {
     ) -> Result<(), ()> {
         let player_id = self.player_mut(player).ok_or(())?;
         let weapon_id = self.weapon_mut(weapon).ok_or(())?;
-        *world.players[player_id].weapon_mut(hand) = Some(weapon_id);
+        world.assign_weapon(player_id, weapon_id, hand)
+    }
+
+    pub fn assign_holster(
+        &mut self,
+        player: PlayerName,
+        weapon: WeaponName,
+        slot: u8,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        let weapon_id = self.weapon_mut(weapon).ok_or(())?;
+        world.assign_holster(player_id, weapon_id, slot)
+    }
+
+    pub fn holster(
+        &mut self,
+        player: PlayerName,
+        hand: Hand,
+        slot: u8,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        world.holster(player_id, hand, slot)
+    }
+
+    pub fn draw(
+        &mut self,
+        player: PlayerName,
+        hand: Hand,
+        slot: u8,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        world.draw(player_id, hand, slot)
+    }
+
+    pub fn pick_up_weapon(
+        &mut self,
+        player: PlayerName,
+        hand: Hand,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        world.pick_up_weapon(player_id, hand)
+    }
+
+    pub fn reload(
+        &mut self,
+        player: PlayerName,
+        hand: Hand,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        world.reload(player_id, hand)
+    }
+
+    pub fn pick_up_ammo(
+        &mut self,
+        player: PlayerName,
+        planet: PlanetName,
+        location: LocationName,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        let planet_id = self.planet_mut(planet).ok_or(())?;
+        world.pick_up_ammo(player_id, planet_id, location as u8)
+    }
+
+    pub fn stock_ammo(
+        &mut self,
+        planet: PlanetName,
+        location: LocationName,
+        n: u16,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let planet_id = self.planet_mut(planet).ok_or(())?;
+        let spaceport_id = world.planets[planet_id].spaceports[location as usize].ok_or(())?;
+        world.spaceports[spaceport_id].ammo_packs = n;
         Ok(())
     }
 
@@ -284,13 +377,33 @@ impl State {
         world: &mut World
     ) -> Result<(), ()> {
         let player_id = self.player_mut(player).ok_or(())?;
-        match hand {
-            Hand::Left => world.players[player_id].left_weapon = None,
-            Hand::Right => world.players[player_id].right_weapon = None,
-        }
+        world.drop_weapon(player_id, hand);
         Ok(())
     }
 
+    pub fn pick_up_medkit(
+        &mut self,
+        player: PlayerName,
+        city: CityName,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        let city_id = self.city_mut(city).ok_or(())?;
+        world.pick_up_medkit(player_id, city_id)
+    }
+
+    pub fn heal(
+        &mut self,
+        healer: PlayerName,
+        hand: Hand,
+        target: PlayerName,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let healer_id = self.player_mut(healer).ok_or(())?;
+        let target_id = self.player_mut(target).ok_or(())?;
+        world.heal(healer_id, hand, target_id)
+    }
+
     pub fn assign_species(
         &mut self,
         player: PlayerName,
@@ -309,7 +422,7 @@ impl State {
         world: &mut World
     ) -> Result<(), ()> {
         let planet_id = self.planet_mut(planet).ok_or(())?;
-        world.planets[planet_id].destroyed = true;
+        world.destroy_planet(planet_id, None);
         Ok(())
     }
 
@@ -319,7 +432,7 @@ impl State {
         world: &mut World
     ) -> Result<(), ()> {
         let player_id = self.player_mut(player).ok_or(())?;
-        world.players[player_id].dead = true;
+        world.kill_player(player_id);
         Ok(())
     }
 
@@ -338,6 +451,18 @@ impl State {
         *self.spaceship_mut(spaceship) = Some(id);
     }
 
+    pub fn board_spaceship(
+        &mut self,
+        player: PlayerName,
+        spaceship: SpaceshipName,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        let spaceship_id = self.spaceship_mut(spaceship).ok_or(())?;
+        world.board_spaceship(player_id, spaceship_id);
+        Ok(())
+    }
+
     pub fn canon_mut(&mut self, canon: CanonName) -> &mut Option<usize> {
         match canon {
             SR6 => &mut self.sr6,
@@ -365,4 +490,212 @@ impl State {
         *world.spaceships[spaceship_id].canon_mut(canon_slot) = Some(canon_id);
         Ok(())
     }
+
+    pub fn fire_canon(
+        &mut self,
+        player: PlayerName,
+        canon_slot: CanonSlot,
+        planet: PlanetName,
+        location: LocationName,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        let planet_id = self.planet_mut(planet).ok_or(())?;
+        world.fire_canon(player_id, canon_slot, planet_id, location as u8)
+    }
+
+    pub fn team_mut(&mut self, team: TeamName) -> &mut Option<usize> {
+        match team {
+            Red => &mut self.red,
+            Blue => &mut self.blue,
+            Green => &mut self.green,
+        }
+    }
+
+    pub fn create_team(
+        &mut self,
+        team: TeamName,
+        world: &mut World
+    ) {
+        let id = world.create_team();
+        *self.team_mut(team) = Some(id);
+    }
+
+    pub fn assign_team(
+        &mut self,
+        player: PlayerName,
+        team: TeamName,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        let team_id = self.team_mut(team).ok_or(())?;
+        world.players[player_id].team = Some(team_id);
+        Ok(())
+    }
+
+    pub fn ally_teams(
+        &mut self,
+        a: TeamName,
+        b: TeamName,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let a = self.team_mut(a).ok_or(())?;
+        let b = self.team_mut(b).ok_or(())?;
+        world.ally_teams(a, b)
+    }
+
+    /// Creates the game mode by name.
+    pub fn game_mode(&mut self, name: GameModeName) -> Option<Box<dyn GameMode>> {
+        Some(match name {
+            GameModeName::DeathMatch => Box::new(DeathMatch),
+            GameModeName::TeamMatch => Box::new(TeamMatch),
+            GameModeName::PlanetDefense(planet) => {
+                Box::new(PlanetDefense {planet: (*self.planet_mut(planet))?})
+            }
+            GameModeName::CaptureTheSpaceport => Box::new(CaptureTheSpaceport),
+        })
+    }
+
+    /// Looks up the name of a winner.
+    pub fn winner_name(&mut self, winner: Winner) -> Option<WinnerName> {
+        match winner {
+            Winner::Player(id) => {
+                for &player in PlayerName::all() {
+                    if *self.player_mut(player) == Some(id) {
+                        return Some(WinnerName::Player(player));
+                    }
+                }
+            }
+            Winner::Team(id) => {
+                for &team in TeamName::all() {
+                    if *self.team_mut(team) == Some(id) {
+                        return Some(WinnerName::Team(team));
+                    }
+                }
+            }
+            Winner::Species(id) => {
+                for &species in SpeciesName::all() {
+                    if *self.species_mut(species) == Some(id) {
+                        return Some(WinnerName::Species(species));
+                    }
+                }
+            }
+            Winner::Invaders => return Some(WinnerName::Invaders),
+            Winner::Defenders => return Some(WinnerName::Defenders),
+        }
+        None
+    }
+
+    pub fn loadout_mut(&mut self, loadout: LoadoutName) -> &mut Option<usize> {
+        match loadout {
+            Assault => &mut self.assault,
+            Sniper => &mut self.sniper,
+        }
+    }
+
+    pub fn create_loadout(
+        &mut self,
+        loadout: LoadoutName,
+        world: &mut World
+    ) {
+        let id = world.create_loadout();
+        *self.loadout_mut(loadout) = Some(id);
+    }
+
+    pub fn set_loadout_weapon(
+        &mut self,
+        loadout: LoadoutName,
+        weapon: WeaponName,
+        hand: Hand,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let loadout_id = self.loadout_mut(loadout).ok_or(())?;
+        let weapon_id = self.weapon_mut(weapon).ok_or(())?;
+        *world.loadouts[loadout_id].weapon_mut(hand) = Some(weapon_id);
+        Ok(())
+    }
+
+    pub fn set_loadout_holster(
+        &mut self,
+        loadout: LoadoutName,
+        weapon: WeaponName,
+        slot: u8,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let loadout_id = self.loadout_mut(loadout).ok_or(())?;
+        let weapon_id = self.weapon_mut(weapon).ok_or(())?;
+        world.set_loadout_holster(loadout_id, weapon_id, slot);
+        Ok(())
+    }
+
+    pub fn restrict_loadout(
+        &mut self,
+        loadout: LoadoutName,
+        species: SpeciesName,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let loadout_id = self.loadout_mut(loadout).ok_or(())?;
+        let species_id = self.species_mut(species).ok_or(())?;
+        world.loadouts[loadout_id].species = Some(species_id);
+        Ok(())
+    }
+
+    pub fn select_loadout(
+        &mut self,
+        player: PlayerName,
+        loadout: LoadoutName,
+        world: &mut World
+    ) -> Result<(), ()> {
+        let player_id = self.player_mut(player).ok_or(())?;
+        let loadout_id = self.loadout_mut(loadout).ok_or(())?;
+        world.select_loadout(player_id, loadout_id)
+    }
+
+    /// Looks up the name of a target.
+    pub fn target_name(&mut self, target: Target, world: &World) -> Option<TargetName> {
+        match target {
+            Target::Player(id) => return self.player_name(id).map(TargetName::Player),
+            Target::Spaceship(id) => {
+                for &spaceship in SpaceshipName::all() {
+                    if *self.spaceship_mut(spaceship) == Some(id) {
+                        return Some(TargetName::Spaceship(spaceship));
+                    }
+                }
+            }
+            Target::Planet(id) => {
+                for &planet in PlanetName::all() {
+                    if *self.planet_mut(planet) == Some(id) {
+                        return Some(TargetName::Planet(planet));
+                    }
+                }
+            }
+            Target::Spaceport(id) => {
+                for &planet in PlanetName::all() {
+                    if let Some(planet_id) = *self.planet_mut(planet) {
+                        for &location in LocationName::all() {
+                            if world.planets[planet_id].spaceports[location as usize] == Some(id) {
+                                return Some(TargetName::Spaceport(planet, location));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Looks up the name of a player.
+    pub fn player_name(&mut self, id: usize) -> Option<PlayerName> {
+        PlayerName::all().iter().cloned().find(|&player| *self.player_mut(player) == Some(id))
+    }
+
+    /// Looks up the name of a weapon type.
+    pub fn weapon_name(&mut self, id: usize) -> Option<WeaponName> {
+        WeaponName::all().iter().cloned().find(|&weapon| *self.weapon_mut(weapon) == Some(id))
+    }
+
+    /// Looks up the name of a canon.
+    pub fn canon_name(&mut self, id: usize) -> Option<CanonName> {
+        CanonName::all().iter().cloned().find(|&canon| *self.canon_mut(canon) == Some(id))
+    }
 }
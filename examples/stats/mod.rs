@@ -0,0 +1,116 @@
+//! Per-player combat statistics and the scoreboard.
+//!
+//! Statistics are fed by the combat functions of `World`,
+//! so they survive changes to the rest of the world state.
+
+use std::collections::HashMap;
+
+use world::{Hand, World};
+
+/// How long damage to a player counts towards an assist when it dies.
+pub const ASSIST_MILLISECONDS: u64 = 10_000;
+/// People killed per war crime point.
+pub const WAR_CRIME_CASUALTIES: u64 = 100;
+/// War crime points for destroying a planet.
+pub const WAR_CRIME_PLANET_POINTS: u32 = 100;
+
+/// What a player attacked with.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Cause {
+    Weapon(usize),
+    Canon(usize),
+}
+
+/// An attack by a player.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Attack {
+    pub attacker: usize,
+    pub cause: Cause,
+    /// The hand holding the weapon, if any.
+    pub hand: Option<Hand>,
+}
+
+/// How a player died.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Death {
+    /// The attack that killed the player, or `None` for deaths without a cause.
+    pub attack: Option<Attack>,
+    /// Other players who damaged the player shortly before it died.
+    pub assists: Vec<usize>,
+}
+
+/// Combat statistics of a player.
+#[derive(Clone, Default, Debug)]
+pub struct Stats {
+    /// Other players killed.
+    pub kills: u32,
+    pub deaths: u32,
+    /// Deaths of other players that player contributed damage to.
+    pub assists: u32,
+    pub shots_fired: u32,
+    /// Shots fired that hit something.
+    pub shots_hit: u32,
+    /// Life taken from other players, by what dealt the damage.
+    pub damage: HashMap<Cause, u32>,
+    pub planets_destroyed: u32,
+    /// People killed in cities, by planet and species.
+    pub casualties: HashMap<(usize, usize), u64>,
+}
+
+impl Stats {
+    /// Returns the percentage of shots fired that hit.
+    pub fn accuracy_percent(&self) -> u32 {
+        if self.shots_fired == 0 {return 0};
+        self.shots_hit * 100 / self.shots_fired
+    }
+
+    /// Returns the war crime score, counting people killed
+    /// and planets destroyed, which game modes can use for penalties.
+    pub fn war_crime_score(&self) -> u32 {
+        let casualty_points = (self.people_killed() / WAR_CRIME_CASUALTIES).min(u32::MAX as u64) as u32;
+        casualty_points.saturating_add(self.planets_destroyed.saturating_mul(WAR_CRIME_PLANET_POINTS))
+    }
+
+    /// Returns the people killed in total.
+    pub fn people_killed(&self) -> u64 {
+        self.casualties.values().fold(0, |sum, &n| sum.saturating_add(n))
+    }
+
+    /// Returns the life taken from other players in total.
+    pub fn damage_dealt(&self) -> u32 {
+        self.damage.values().sum()
+    }
+}
+
+/// A player's row in the scoreboard.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ScoreboardRow {
+    pub player: usize,
+    pub score: u32,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub damage: u32,
+    pub accuracy_percent: u32,
+}
+
+/// Returns the scoreboard, ranking players by score,
+/// then by kills, then by fewest deaths.
+pub fn scoreboard(world: &World) -> Vec<ScoreboardRow> {
+    let mut rows: Vec<ScoreboardRow> = world.players.iter().enumerate()
+        .map(|(i, player)| ScoreboardRow {
+            player: i,
+            score: world.game_mode.score(world, i),
+            kills: player.stats.kills,
+            deaths: player.stats.deaths,
+            assists: player.stats.assists,
+            damage: player.stats.damage_dealt(),
+            accuracy_percent: player.stats.accuracy_percent(),
+        })
+        .collect();
+    rows.sort_by(|a, b| b.score.cmp(&a.score)
+        .then(b.kills.cmp(&a.kills))
+        .then(a.deaths.cmp(&b.deaths))
+        .then(a.player.cmp(&b.player)));
+    rows
+}
@@ -458,7 +458,7 @@ pub fn death_match_winner() -> (Vec<Expr>, Vec<Expr>) {
             OutOfGame(Alice, true),
             OutOfGame(Bob, false),
             NumberOfPlayersLeft(1),
-            DeathMatchWinner(Bob),
+            MatchWinner(WinnerName::Player(Bob)),
             Sound,
         ]
     )
@@ -486,6 +486,7 @@ pub fn team_match_winner() -> (Vec<Expr>, Vec<Expr>) {
             CreatePlayer(Carl),
             AssignSpecies(Carl, Ralm),
             AssignWeapon(Carl, XV43, Hand::Left),
+            SelectGameMode(GameModeName::TeamMatch),
 
             // Alice's spawning planet gets destroyed and then she gets killed.
             DestroyPlanet(Tellar),
@@ -496,7 +497,7 @@ pub fn team_match_winner() -> (Vec<Expr>, Vec<Expr>) {
             OutOfGame(Bob, false),
             OutOfGame(Carl, false),
             NumberOfPlayersLeft(2),
-            TeamMatchWinner(Ralm),
+            MatchWinner(WinnerName::Species(Ralm)),
             Sound,
         ]
     )
@@ -792,8 +793,4291 @@ pub fn recharge_milliseconds_all_weapons() -> (Vec<Expr>, Vec<Expr>) {
     )
 }
 
+pub fn update_head_pose() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            // Alice looks to the left.
+            UpdatePose(Alice, Tracker::Head, [0, 1600, 0], [7071, 0, 7071, 0]),
+        ],
+        vec![
+            IsOnPlanet(Alice, Tellar),
+            HasPose(Alice, Tracker::Head, [0, 1600, 0], [7071, 0, 7071, 0]),
+            Sound,
+        ]
+    )
+}
+
+pub fn distance_between_players_on_same_planet() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Head, [0, 1700, 0], [10000, 0, 0, 0]),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Head, [3000, 1700, 4000], [10000, 0, 0, 0]),
+        ],
+        vec![
+            DistanceBetween(Alice, Bob, 5),
+            DistanceBetween(Bob, Alice, 5),
+            Sound,
+        ]
+    )
+}
+
+pub fn board_spaceship() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceship(Folkum),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            BoardSpaceship(Alice, Folkum),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [300, 1000, -200], [10000, 0, 0, 0]),
+        ],
+        vec![
+            IsOnSpaceship(Alice, Folkum),
+            HasPose(Alice, Tracker::Hand(Hand::Right), [300, 1000, -200], [10000, 0, 0, 0]),
+            Sound,
+        ]
+    )
+}
+
+pub fn fire_at_player() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Right),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [0, 1500, 0], [10000, 0, 0, 0]),
+
+            // Bob stands 10 meters in front of Alice, Carl stands behind Bob.
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Head, [100, 1700, -10000], [10000, 0, 0, 0]),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Ralm),
+            Spawn(Carl),
+            UpdatePose(Carl, Tracker::Head, [0, 1700, -20000], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Right),
+        ],
+        vec![
+            LastHit(Alice, Hand::Right, TargetName::Player(Bob)),
+            HasLife(Bob, 900),
+            HasLife(Carl, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn fire_at_planet() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            Spawn(Alice),
+            // Point at the ground.
+            UpdatePose(Alice, Tracker::Hand(Hand::Left), [0, 1000, 0], [7071, -7071, 0, 0]),
+
+            Fire(Alice, Hand::Left),
+        ],
+        vec![
+            LastHit(Alice, Hand::Left, TargetName::Planet(Tellar)),
+            IsPlanetDestroyed(Tellar, true),
+            Sound,
+        ]
+    )
+}
+
+pub fn fire_at_spaceship() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            SetPlanetRadius(Tellar, 5000),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            // Folkum hovers 200 meters above the surface.
+            CreateSpaceship(Folkum),
+            SetSpaceshipPosition(Folkum, [0, 5_000_200, 0]),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            // Point at the sky.
+            UpdatePose(Alice, Tracker::Hand(Hand::Left), [0, 1000, 0], [7071, 7071, 0, 0]),
+
+            Fire(Alice, Hand::Left),
+        ],
+        vec![
+            LastHit(Alice, Hand::Left, TargetName::Spaceship(Folkum)),
+            Sound,
+        ]
+    )
+}
+
+pub fn fire_at_nothing() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            SetPlanetPosition(Munos, [0, 0, 100_000]),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponRechargeMilliseconds(XV43, 1000),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            // Point away from Munos.
+            UpdatePose(Alice, Tracker::Hand(Hand::Left), [0, 1000, 0], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Left),
+        ],
+        vec![
+            MillisecondsToRecharge(Alice, Hand::Left, 1000),
+            IsPlanetDestroyed(Munos, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn projectile_in_flight() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 100),
+            SetWeaponProjectileSpeed(TT180, 10),
+            SetWeaponProjectileLifetime(TT180, 3000),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, TT180, Hand::Right),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [0, 1500, 0], [10000, 0, 0, 0]),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Head, [0, 1700, -20000], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Right),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            NumberOfProjectiles(1),
+            HasLife(Bob, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn projectile_hits_player() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 100),
+            SetWeaponProjectileSpeed(TT180, 10),
+            SetWeaponProjectileLifetime(TT180, 3000),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, TT180, Hand::Right),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [0, 1500, 0], [10000, 0, 0, 0]),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Head, [0, 1700, -20000], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Right),
+            UpdateMilliseconds(1000),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            NumberOfProjectiles(0),
+            LastHit(Alice, Hand::Right, TargetName::Player(Bob)),
+            HasLife(Bob, 900),
+            Sound,
+        ]
+    )
+}
+
+pub fn dodge_projectile() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 100),
+            SetWeaponProjectileSpeed(TT180, 10),
+            SetWeaponProjectileLifetime(TT180, 3000),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, TT180, Hand::Right),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [0, 1500, 0], [10000, 0, 0, 0]),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Head, [0, 1700, -20000], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Right),
+            UpdateMilliseconds(1000),
+            // Bob steps aside.
+            UpdatePose(Bob, Tracker::Head, [2000, 1700, -20000], [10000, 0, 0, 0]),
+            UpdateMilliseconds(1000),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            NumberOfProjectiles(0),
+            HasLife(Bob, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn projectile_hits_planet() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            SetPlanetRadius(Munos, 1000),
+            SetPlanetPosition(Munos, [0, 6000, -100_000]),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetWeaponProjectileSpeed(AM0, 5_000_000),
+            SetWeaponProjectileLifetime(AM0, 60_000),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            Spawn(Alice),
+            // Aim at Munos, which is 100 000 km away.
+            UpdatePose(Alice, Tracker::Hand(Hand::Left), [0, 1000, 0], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Left),
+            UpdateMilliseconds(60_000),
+        ],
+        vec![
+            LastHit(Alice, Hand::Left, TargetName::Planet(Munos)),
+            IsPlanetDestroyed(Munos, true),
+            IsPlanetDestroyed(Tellar, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn miss_with_zero_accuracy() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetWeaponAccuracy(XV43, 0),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasLife(Bob, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn damage_variance_with_pinned_seed() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            SetSeed(42),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetWeaponDamageVariance(XV43, 50),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasLife(Bob, 814),
+            Sound,
+        ]
+    )
+}
+
+pub fn spread_with_pinned_seed() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            SetSeed(7),
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetWeaponSpread(XV43, 200),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Right),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [0, 1500, 0], [10000, 0, 0, 0]),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Head, [0, 1700, -10000], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Right),
+            Fire(Alice, Hand::Right),
+            Fire(Alice, Hand::Right),
+            Fire(Alice, Hand::Right),
+        ],
+        vec![
+            // Only one of four shots hits.
+            HasLife(Bob, 900),
+            Sound,
+        ]
+    )
+}
+
+pub fn headshot() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Right),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [0, 1700, 0], [10000, 0, 0, 0]),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Head, [0, 1700, -10000], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Right),
+        ],
+        vec![
+            LastHitZone(Bob, HitZone::Head),
+            HasLife(Bob, 800),
+            Sound,
+        ]
+    )
+}
+
+pub fn hand_hit_drops_weapon() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Right),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [500, 1200, 0], [10000, 0, 0, 0]),
+            // Bob holds his weapon out to the side.
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignWeapon(Bob, XV43, Hand::Right),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Head, [0, 1700, -10000], [10000, 0, 0, 0]),
+            UpdatePose(Bob, Tracker::Hand(Hand::Right), [500, 1200, -10000], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Right),
+        ],
+        vec![
+            LastHitZone(Bob, HitZone::Hand(Hand::Right)),
+            HandEmpty(Bob, Hand::Right),
+            HasLife(Bob, 950),
+            Sound,
+        ]
+    )
+}
+
+pub fn lethal_headshot_rule() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            SetHitZoneDamage(HitZone::Head, 1000),
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Right),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [0, 1700, 0], [10000, 0, 0, 0]),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Head, [0, 1700, -10000], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Right),
+        ],
+        vec![
+            HasLife(Bob, 0),
+            IsDead(Bob, true),
+            Sound,
+        ]
+    )
+}
+
+pub fn shield_absorbs_damage() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            SetShield(Bob, 300),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasShield(Bob, 100),
+            HasLife(Bob, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn damage_through_shield_armor_and_life() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 200),
+            SetWeaponDamageType(XV43, DamageType::Kinetic),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            SetShield(Bob, 50),
+            SetShieldResistance(Bob, DamageType::Kinetic, 0),
+            SetArmor(Bob, 100),
+            SetArmorResistance(Bob, DamageType::Kinetic, 50),
+
+            // Shield absorbs 50, armor absorbs half of the remaining 150.
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasShield(Bob, 0),
+            HasArmor(Bob, 25),
+            HasLife(Bob, 925),
+            Sound,
+        ]
+    )
+}
+
+pub fn shield_resists_energy_damage() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 100),
+            SetWeaponDamageType(TT180, DamageType::Energy),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            SetShield(Bob, 100),
+            SetShieldResistance(Bob, DamageType::Energy, 50),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasShield(Bob, 50),
+            HasLife(Bob, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn shield_regenerates() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            SetShield(Bob, 300),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            // Shield starts regenerating 3 seconds after taking damage.
+            UpdateMilliseconds(3000),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            HasShield(Bob, 250),
+            Sound,
+        ]
+    )
+}
+
+pub fn life_regenerates() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            // Life starts regenerating 5 seconds after taking damage.
+            UpdateMilliseconds(5000),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            HasLife(Bob, 920),
+            Sound,
+        ]
+    )
+}
+
+pub fn life_regeneration_is_capped() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            SetMaxLife(950),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            UpdateMilliseconds(5000),
+            UpdateMilliseconds(10000),
+        ],
+        vec![
+            HasLife(Bob, 950),
+            Sound,
+        ]
+    )
+}
+
+pub fn pick_up_medkit() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            StockMedkits(Eldonar, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            SetLife(Alice, 300),
+            PickUpMedkit(Alice, Eldonar),
+        ],
+        vec![
+            HasLife(Alice, 800),
+            HasMedkits(Eldonar, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_pick_up_medkit_far_from_city() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::B),
+            StockMedkits(Eldonar, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            SetLife(Alice, 300),
+            PickUpMedkit(Alice, Eldonar),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn heal_teammate() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(TT180),
+            SetWeaponHealing(TT180, 200),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            SetLife(Bob, 500),
+
+            Heal(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasLife(Bob, 700),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_heal_enemy() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateWeapon(TT180),
+            SetWeaponHealing(TT180, 200),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            SetLife(Bob, 500),
+
+            Heal(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn stun_blocks_shooting() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 100),
+            SetWeaponStatusEffect(TT180, StatusEffect::Stunned, 2000),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtPlayer(Bob, Hand::Right, Alice),
+        ],
+        vec![
+            HasStatus(Bob, StatusEffect::Stunned, 2000),
+            CanShoot(Bob, Hand::Right, false),
+            HasLife(Alice, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn stun_wears_off() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 100),
+            SetWeaponStatusEffect(TT180, StatusEffect::Stunned, 2000),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            UpdateMilliseconds(1500),
+            UpdateMilliseconds(500),
+            ShootAtPlayer(Bob, Hand::Right, Alice),
+        ],
+        vec![
+            HasLife(Alice, 900),
+            Sound,
+        ]
+    )
+}
+
+pub fn burning_damage() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 100),
+            SetWeaponStatusEffect(TT180, StatusEffect::Burning, 3000),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            UpdateMilliseconds(2000),
+        ],
+        vec![
+            HasStatus(Bob, StatusEffect::Burning, 1000),
+            HasLife(Bob, 800),
+            Sound,
+        ]
+    )
+}
+
+pub fn emp_blocks_recharge() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponRechargeMilliseconds(XV43, 1000),
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 0),
+            SetWeaponStatusEffect(TT180, StatusEffect::Emp, 2000),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+
+            ShootAtNothing(Bob, Hand::Right),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            MillisecondsToRecharge(Bob, Hand::Right, 1000),
+            CanShoot(Bob, Hand::Right, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn slowed_recharge() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponRechargeMilliseconds(XV43, 1000),
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 0),
+            SetWeaponStatusEffect(TT180, StatusEffect::Slowed, 2000),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+
+            ShootAtNothing(Bob, Hand::Right),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            MillisecondsToRecharge(Bob, Hand::Right, 500),
+            Sound,
+        ]
+    )
+}
+
+pub fn magazine_runs_empty() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetWeaponMagazine(XV43, 2),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            RechargeMillisecondsAllWeapons(1000),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            RechargeMillisecondsAllWeapons(1000),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            AmmoLeft(Alice, Hand::Left, 0),
+            CanShoot(Alice, Hand::Left, false),
+            HasLife(Bob, 800),
+            Sound,
+        ]
+    )
+}
+
+pub fn reload_from_spare_ammo() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponMagazine(XV43, 10),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            SetSpareAmmo(Alice, 5),
+
+            ShootAtNothing(Alice, Hand::Left),
+            ShootAtNothing(Alice, Hand::Left),
+            ShootAtNothing(Alice, Hand::Left),
+            Reload(Alice, Hand::Left),
+        ],
+        vec![
+            AmmoLeft(Alice, Hand::Left, 10),
+            SpareAmmo(Alice, 2),
+            Sound,
+        ]
+    )
+}
+
+pub fn reload_takes_time() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponMagazine(XV43, 10),
+            SetWeaponReloadMilliseconds(XV43, 1500),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            SetSpareAmmo(Alice, 10),
+
+            ShootAtNothing(Alice, Hand::Left),
+            Reload(Alice, Hand::Left),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            MillisecondsToRecharge(Alice, Hand::Left, 500),
+            CanShoot(Alice, Hand::Left, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn pick_up_ammo_at_spaceport() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            StockAmmo(Tellar, LocationName::A, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            PickUpAmmo(Alice, Tellar, LocationName::A),
+        ],
+        vec![
+            SpareAmmo(Alice, AMMO_PACK_ROUNDS),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_pick_up_ammo_at_destroyed_spaceport() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            StockAmmo(Tellar, LocationName::A, 1),
+            DestroySpaceport(Tellar, LocationName::A),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            PickUpAmmo(Alice, Tellar, LocationName::A),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn weapon_items_have_separate_ammo() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponMagazine(XV43, 2),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+
+            ShootAtNothing(Alice, Hand::Left),
+            RechargeMillisecondsAllWeapons(1000),
+            ShootAtNothing(Alice, Hand::Left),
+        ],
+        vec![
+            AmmoLeft(Alice, Hand::Left, 0),
+            AmmoLeft(Bob, Hand::Right, 2),
+            CanShoot(Bob, Hand::Right, true),
+            NumberOfWeaponUsers(XV43, 2),
+            Sound,
+        ]
+    )
+}
+
+pub fn dropping_weapon_item_keeps_other_users() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+
+            DropWeapon(Alice, Hand::Left),
+        ],
+        vec![
+            NumberOfWeaponUsers(XV43, 1),
+            HasWeapon(Bob, XV43),
+            HandEmpty(Alice, Hand::Left),
+            Sound,
+        ]
+    )
+}
+
+pub fn dropped_weapon_lies_on_planet() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            DropWeapon(Alice, Hand::Left),
+        ],
+        vec![
+            WeaponLyingOn(XV43, Tellar),
+            Sound,
+        ]
+    )
+}
+
+pub fn pick_up_dropped_weapon() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponMagazine(XV43, 10),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            ShootAtNothing(Alice, Hand::Left),
+            DropWeapon(Alice, Hand::Left),
+
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Hand(Hand::Right), [1000, 1000, 0], [10000, 0, 0, 0]),
+            PickUpWeapon(Bob, Hand::Right),
+        ],
+        vec![
+            HasWeapon(Bob, XV43),
+            AmmoLeft(Bob, Hand::Right, 9),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_pick_up_weapon_out_of_reach() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            DropWeapon(Alice, Hand::Left),
+
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            UpdatePose(Bob, Tracker::Hand(Hand::Right), [5000, 1000, 0], [10000, 0, 0, 0]),
+            PickUpWeapon(Bob, Hand::Right),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_pick_up_despawned_weapon() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            DropWeapon(Alice, Hand::Left),
+            UpdateMilliseconds(30_000),
+            PickUpWeapon(Alice, Hand::Left),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn holster_weapon() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Holster(Alice, Hand::Left, 1),
+        ],
+        vec![
+            HandEmpty(Alice, Hand::Left),
+            Holstered(Alice, 1, XV43),
+            HasWeapon(Alice, XV43),
+            Sound,
+        ]
+    )
+}
+
+pub fn draw_weapon_takes_swap_time() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+
+            CreatePlayer(Alice),
+            AssignHolster(Alice, XV43, 0),
+            Draw(Alice, Hand::Right, 0),
+            UpdateMilliseconds(200),
+        ],
+        vec![
+            HasWeapon(Alice, XV43),
+            MillisecondsToRecharge(Alice, Hand::Right, HOLSTER_SWAP_MILLISECONDS - 200),
+            CanShoot(Alice, Hand::Right, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_holster_in_missing_slot() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Holster(Alice, Hand::Left, DEFAULT_HOLSTER_SLOTS),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn extra_holster_slot() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            CreateWeapon(TT180),
+            CreateWeapon(AM0),
+
+            CreatePlayer(Alice),
+            SetHolsterSlots(Alice, 3),
+            AssignHolster(Alice, XV43, 0),
+            AssignHolster(Alice, TT180, 1),
+            AssignHolster(Alice, AM0, 2),
+        ],
+        vec![
+            Holstered(Alice, 2, AM0),
+            Sound,
+        ]
+    )
+}
+
+pub fn holstered_weapons_count_as_weapons() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+
+            CreatePlayer(Alice),
+            AssignHolster(Alice, XV43, 0),
+        ],
+        vec![
+            HasWeapons(Alice, true),
+            AllPlayersHaveWeapons(true),
+            NumberOfWeaponUsers(XV43, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn spawn_with_loadout() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            CreateWeapon(TT180),
+            CreateLoadout(Assault),
+            SetLoadoutWeapon(Assault, XV43, Hand::Right),
+            SetLoadoutHolster(Assault, TT180, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            SelectLoadout(Alice, Assault),
+            Spawn(Alice),
+        ],
+        vec![
+            HasLoadout(Alice, Assault),
+            HasWeapon(Alice, XV43),
+            HandEmpty(Alice, Hand::Left),
+            Holstered(Alice, 1, TT180),
+            Sound,
+        ]
+    )
+}
+
+pub fn respawn_with_fresh_loadout() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponMagazine(XV43, 10),
+            CreateLoadout(Assault),
+            SetLoadoutWeapon(Assault, XV43, Hand::Right),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            SelectLoadout(Alice, Assault),
+            Spawn(Alice),
+            ShootAtNothing(Alice, Hand::Right),
+            Spawn(Alice),
+        ],
+        vec![
+            AmmoLeft(Alice, Hand::Right, 10),
+            Sound,
+        ]
+    )
+}
+
+pub fn loadout_restricted_to_species() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateWeapon(XV43),
+            CreateLoadout(Sniper),
+            SetLoadoutWeapon(Sniper, XV43, Hand::Right),
+            RestrictLoadout(Sniper, Vatrax),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            SelectLoadout(Alice, Sniper),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn loadout_allowed_for_species() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateWeapon(XV43),
+            CreateLoadout(Sniper),
+            SetLoadoutWeapon(Sniper, XV43, Hand::Right),
+            RestrictLoadout(Sniper, Vatrax),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Vatrax),
+            SelectLoadout(Alice, Sniper),
+        ],
+        vec![
+            HasLoadout(Alice, Sniper),
+            LoadoutValid(Sniper, true),
+            Sound,
+        ]
+    )
+}
+
+pub fn loadout_with_two_planet_destroyers_is_invalid() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            CreateLoadout(Assault),
+            SetLoadoutWeapon(Assault, AM0, Hand::Left),
+            SetLoadoutWeapon(Assault, AM0, Hand::Right),
+
+            CreatePlayer(Alice),
+            SelectLoadout(Alice, Assault),
+        ],
+        vec![
+            LoadoutValid(Assault, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_destroyer_limit() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetPlanetDestroyerLimit(1),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, AM0, Hand::Left),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_destroyer_charges_before_strike() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetWeaponChargeMilliseconds(AM0, 5000),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            CreatePlanet(Tellar),
+            CreateSpaceship(Folkum),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+            UpdateMilliseconds(2000),
+        ],
+        vec![
+            PlanetDestroyerAnnounced(Alice, Tellar),
+            Charging(Alice, Tellar, 3000),
+            IsPlanetDestroyed(Tellar, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_destroyer_charge_completes() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetWeaponChargeMilliseconds(AM0, 5000),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            CreatePlanet(Tellar),
+            CreateSpaceship(Folkum),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+            UpdateMilliseconds(5000),
+        ],
+        vec![
+            IsPlanetDestroyed(Tellar, true),
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_destroyer_charge_interrupted_by_damage() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetWeaponChargeMilliseconds(AM0, 5000),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            CreatePlanet(Tellar),
+            CreateSpaceship(Folkum),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+            UpdateMilliseconds(2000),
+            ShootAtPlayer(Bob, Hand::Right, Alice),
+            UpdateMilliseconds(5000),
+        ],
+        vec![
+            PlanetDestroyerInterrupted(Alice, Tellar),
+            IsPlanetDestroyed(Tellar, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_destroyer_requires_spaceship() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            RequireSpaceshipForPlanetDestroyer(true),
+            CreatePlanet(Tellar),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_destroyer_aboard_spaceship() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            RequireSpaceshipForPlanetDestroyer(true),
+            CreatePlanet(Tellar),
+            CreateSpaceship(Folkum),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+        ],
+        vec![
+            IsPlanetDestroyed(Tellar, true),
+            Sound,
+        ]
+    )
+}
+
+pub fn canon_hits_spaceport() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 1000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+        ],
+        vec![
+            SpaceportHull(Tellar, LocationName::A, DEFAULT_SPACEPORT_HULL - 1000),
+            HasLife(Bob, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn canon_blast_damages_players_and_spaceports() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 1000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            SetCanonBlastRadius(SR6, 100),
+            UpdatePose(Bob, Tracker::Head, [0, 0, 10_000], [10000, 0, 0, 0]),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+        ],
+        vec![
+            BlastHit(TargetName::Spaceport(Tellar, LocationName::A), 1000),
+            BlastHit(TargetName::Player(Bob), 900),
+            HasLife(Bob, 100),
+            Sound,
+        ]
+    )
+}
+
+pub fn blast_falls_off_with_distance() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 1000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            SetCanonBlastRadius(SR6, 100),
+            UpdatePose(Bob, Tracker::Head, [0, 0, 75_000], [10000, 0, 0, 0]),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+        ],
+        vec![
+            HasLife(Bob, 750),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_fire_canon_without_boarding() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 1000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            FireCanon(Bob, CanonSlot::BottomFront, Tellar, LocationName::A),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn spaceport_destroyed_by_canon() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 1000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            SetCanonFirepower(SR6, 10_000),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+        ],
+        vec![
+            HasNumberOfSpaceports(Tellar, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn explosive_hand_weapon() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 400),
+            SetWeaponBlastRadius(TT180, 10),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, TT180, Hand::Right),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Head, [0, 1700, 20_000], [10000, 0, 0, 0]),
+            UpdatePose(Alice, Tracker::Hand(Hand::Right), [0, 1000, 20_000], [10000, 0, 0, 0]),
+
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Ralm),
+            Spawn(Carl),
+            UpdatePose(Carl, Tracker::Head, [0, 1700, -5000], [10000, 0, 0, 0]),
+
+            Fire(Alice, Hand::Right),
+        ],
+        vec![
+            LastHit(Alice, Hand::Right, TargetName::Player(Bob)),
+            BlastHit(TargetName::Player(Bob), 370),
+            BlastHit(TargetName::Player(Carl), 186),
+            HasLife(Alice, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn friendly_fire_full_by_default() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasLife(Bob, 900),
+            Sound,
+        ]
+    )
+}
+
+pub fn friendly_fire_off() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            SetFriendlyFire(FriendlyFire::Off),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasLife(Bob, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn friendly_fire_reduced() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            SetFriendlyFire(FriendlyFire::Reduced),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasLife(Bob, 950),
+            Sound,
+        ]
+    )
+}
+
+pub fn friendly_fire_off_still_damages_enemies() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            SetFriendlyFire(FriendlyFire::Off),
+            ShootAtPlayer(Alice, Hand::Left, Carl),
+        ],
+        vec![
+            HasLife(Carl, 900),
+            Sound,
+        ]
+    )
+}
+
+pub fn self_damage_off() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            SetSelfDamage(false),
+            ShootAtPlayer(Alice, Hand::Left, Alice),
+        ],
+        vec![
+            HasLife(Alice, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn self_damage_on_by_default() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            ShootAtPlayer(Alice, Hand::Left, Alice),
+        ],
+        vec![
+            HasLife(Alice, 900),
+            Sound,
+        ]
+    )
+}
+
+pub fn friendly_fire_off_protects_from_blast() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 1000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            CreateSpecies(Vatrax),
+            AssignSpecies(Alice, Ralm),
+            CreatePlayer(Carl),
+            AssignHomePlanet(Vatrax, Tellar),
+            AssignSpecies(Carl, Vatrax),
+            Spawn(Carl),
+            SetFriendlyFire(FriendlyFire::Off),
+            SetCanonBlastRadius(SR6, 100),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+        ],
+        vec![
+            HasLife(Bob, 1000),
+            BlastHit(TargetName::Player(Carl), 983),
+            Sound,
+        ]
+    )
+}
+
+pub fn same_species_on_opposing_teams() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Vatrax, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetFriendlyFire(FriendlyFire::Off),
+            SelectGameMode(GameModeName::TeamMatch),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+            CreateTeam(Green),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignTeam(Bob, Blue),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            AssignTeam(Carl, Green),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            IsOnTeam(Alice, Red),
+            IsOnTeam(Bob, Blue),
+            HasLife(Bob, 900),
+            Sound,
+        ]
+    )
+}
+
+pub fn different_species_on_same_team() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Vatrax, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetFriendlyFire(FriendlyFire::Off),
+            SelectGameMode(GameModeName::TeamMatch),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+            CreateTeam(Green),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignTeam(Bob, Blue),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            AssignTeam(Carl, Green),
+            AssignTeam(Carl, Red),
+            ShootAtPlayer(Alice, Hand::Left, Carl),
+        ],
+        vec![
+            HasLife(Carl, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn allied_teams_are_friendly() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Vatrax, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetFriendlyFire(FriendlyFire::Off),
+            SelectGameMode(GameModeName::TeamMatch),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+            CreateTeam(Green),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignTeam(Bob, Blue),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            AssignTeam(Carl, Green),
+            AllyTeams(Red, Green),
+            ShootAtPlayer(Alice, Hand::Left, Carl),
+        ],
+        vec![
+            AreAllies(Green, Red),
+            HasLife(Carl, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn winning_team() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Vatrax, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetFriendlyFire(FriendlyFire::Off),
+            SelectGameMode(GameModeName::TeamMatch),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+            CreateTeam(Green),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignTeam(Bob, Blue),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            AssignTeam(Carl, Green),
+            Kill(Bob),
+            Kill(Carl),
+            DestroyPlanet(Tellar),
+        ],
+        vec![
+            MatchWinner(WinnerName::Team(Red)),
+            Sound,
+        ]
+    )
+}
+
+pub fn allied_teams_win_together() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Vatrax, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetFriendlyFire(FriendlyFire::Off),
+            SelectGameMode(GameModeName::TeamMatch),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+            CreateTeam(Green),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignTeam(Bob, Blue),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            AssignTeam(Carl, Green),
+            AllyTeams(Red, Green),
+            Kill(Bob),
+            DestroyPlanet(Tellar),
+        ],
+        vec![
+            MatchWinner(WinnerName::Team(Red)),
+            MatchWinner(WinnerName::Team(Green)),
+            Sound,
+        ]
+    )
+}
+
+pub fn no_winning_team_while_enemies_remain() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Vatrax, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetFriendlyFire(FriendlyFire::Off),
+            SelectGameMode(GameModeName::TeamMatch),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+            CreateTeam(Green),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignTeam(Bob, Blue),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            AssignTeam(Carl, Green),
+            Kill(Carl),
+            DestroyPlanet(Tellar),
+        ],
+        vec![
+            MatchWinner(WinnerName::Team(Red)),
+            Sound,
+        ]
+    )
+}
+
+pub fn death_match_is_default_game_mode() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            SelectGameMode(GameModeName::TeamMatch),
+            SelectGameMode(GameModeName::DeathMatch),
+
+            // Create two planets with two species.
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Vatrax),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Vatrax, Tellar),
+            AssignHomePlanet(Ralm, Munos),
+
+            // Create two players.
+            CreateWeapon(XV43),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Vatrax),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignWeapon(Bob, XV43, Hand::Right),
+
+            // Alice's spawning planet gets destroyed and then she gets killed.
+            DestroyPlanet(Tellar),
+            Kill(Alice),
+        ],
+        vec![
+            MatchWinner(WinnerName::Player(Bob)),
+            Score(Bob, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_defense_lost_when_planet_destroyed() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpecies(Ralm),
+            PopulateCity(Eldonar, 1000, Ralm),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            DestroyPlanet(Tellar),
+        ],
+        vec![
+            MatchWinner(WinnerName::Invaders),
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_defense_not_lost_while_people_live() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpecies(Ralm),
+            PopulateCity(Eldonar, 1000, Ralm),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+        ],
+        vec![
+            MatchWinner(WinnerName::Invaders),
+            Sound,
+        ]
+    )
+}
+
+pub fn capture_the_spaceport_winner() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            SelectGameMode(GameModeName::CaptureTheSpaceport),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            Spawn(Alice),
+            UpdateMilliseconds(10_000),
+        ],
+        vec![
+            IsAtLocation(Alice, Tellar, LocationName::A),
+            SpaceportController(Tellar, LocationName::A, Red),
+            MatchWinner(WinnerName::Team(Red)),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_respawn_without_spawning_planet() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            Kill(Alice),
+            DestroyPlanet(Tellar),
+            Spawn(Alice),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn respawn_revives_player() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            Kill(Alice),
+            Spawn(Alice),
+        ],
+        vec![
+            IsDead(Alice, false),
+            HasLife(Alice, DEFAULT_PLAYER_LIFE),
+            Sound,
+        ]
+    )
+}
+
+pub fn team_match_score() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Vatrax, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            SetFriendlyFire(FriendlyFire::Off),
+            SelectGameMode(GameModeName::TeamMatch),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+            CreateTeam(Green),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignTeam(Bob, Blue),
+            CreatePlayer(Carl),
+            AssignSpecies(Carl, Vatrax),
+            AssignTeam(Carl, Green),
+            Kill(Bob),
+            Kill(Carl),
+            DestroyPlanet(Tellar),
+        ],
+        vec![
+            Score(Alice, 2),
+            Score(Bob, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn lobby_phase() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+        ],
+        vec![
+            MatchPhase(Phase::Lobby),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_shoot_in_lobby() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_spawn_in_lobby() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            Spawn(Alice),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn warmup_then_countdown() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            StartMatch,
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            UpdateMilliseconds(1500),
+        ],
+        vec![
+            HasLife(Bob, 900),
+            MatchPhase(Phase::Countdown),
+            PhaseMillisecondsLeft(500),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_shoot_during_countdown() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            StartMatch,
+            UpdateMilliseconds(1500),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn match_in_progress_after_countdown() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            StartMatch,
+            Spawn(Alice),
+            Spawn(Bob),
+            UpdateMilliseconds(2000),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            MatchPhase(Phase::InProgress),
+            HasLife(Bob, 900),
+            Sound,
+        ]
+    )
+}
+
+pub fn overtime_without_winner() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            StartMatch,
+            Spawn(Alice),
+            Spawn(Bob),
+            UpdateMilliseconds(2000),
+            UpdateMilliseconds(10_000),
+        ],
+        vec![
+            MatchPhase(Phase::Overtime),
+            Sound,
+        ]
+    )
+}
+
+pub fn match_ends_after_overtime() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            StartMatch,
+            Spawn(Alice),
+            Spawn(Bob),
+            UpdateMilliseconds(2000),
+            UpdateMilliseconds(10_000),
+            UpdateMilliseconds(5000),
+        ],
+        vec![
+            MatchPhase(Phase::Ended),
+            Sound,
+        ]
+    )
+}
+
+pub fn match_ends_when_winner_decided() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            StartMatch,
+            Spawn(Alice),
+            Spawn(Bob),
+            UpdateMilliseconds(2000),
+            DestroyPlanet(Munos),
+            Kill(Bob),
+        ],
+        vec![
+            MatchWinner(WinnerName::Player(Alice)),
+            MatchPhase(Phase::Ended),
+            Sound,
+        ]
+    )
+}
+
+pub fn no_damage_after_match_ended() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            StartMatch,
+            Spawn(Alice),
+            Spawn(Bob),
+            UpdateMilliseconds(2000),
+            DestroyPlanet(Munos),
+            Kill(Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_change_team_after_lobby() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            AssignHomePlanet(Ralm, Tellar),
+            AssignHomePlanet(Vatrax, Munos),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            OpenLobby,
+            SetPhaseDuration(Phase::Warmup, 1000),
+            SetPhaseDuration(Phase::Countdown, 1000),
+            SetPhaseDuration(Phase::InProgress, 10_000),
+            SetPhaseDuration(Phase::Overtime, 5000),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Vatrax),
+            CreateTeam(Red),
+            StartMatch,
+            AssignTeam(Alice, Red),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn spaceport_not_captured_before_hold_duration() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            SelectGameMode(GameModeName::CaptureTheSpaceport),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            Spawn(Alice),
+            UpdateMilliseconds(9000),
+        ],
+        vec![
+            SpaceportController(Tellar, LocationName::A, Red),
+            Sound,
+        ]
+    )
+}
+
+pub fn contested_spaceport_not_captured() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            SelectGameMode(GameModeName::CaptureTheSpaceport),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            Spawn(Alice),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignTeam(Bob, Blue),
+            Spawn(Bob),
+            UpdateMilliseconds(10_000),
+        ],
+        vec![
+            SpaceportController(Tellar, LocationName::A, Red),
+            Sound,
+        ]
+    )
+}
+
+pub fn capture_progress_lost_when_leaving() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            SelectGameMode(GameModeName::CaptureTheSpaceport),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            Spawn(Alice),
+            UpdateMilliseconds(9000),
+            UpdatePose(Alice, Tracker::Head, [500_000, 1700, 0], [10000, 0, 0, 0]),
+            UpdateMilliseconds(1000),
+            UpdatePose(Alice, Tracker::Head, [0, 1700, 0], [10000, 0, 0, 0]),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            SpaceportController(Tellar, LocationName::A, Red),
+            Sound,
+        ]
+    )
+}
+
+pub fn controlled_spaceport_scores_over_time() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            SelectGameMode(GameModeName::CaptureTheSpaceport),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            Spawn(Alice),
+            UpdateMilliseconds(10_000),
+            UpdateMilliseconds(2500),
+            UpdateMilliseconds(500),
+        ],
+        vec![
+            Score(Alice, 3),
+            Sound,
+        ]
+    )
+}
+
+pub fn team_has_space_travel_with_controlled_spaceport() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            SelectGameMode(GameModeName::CaptureTheSpaceport),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            Spawn(Alice),
+            UpdateMilliseconds(10_000),
+        ],
+        vec![
+            HasSpaceTravel(Tellar, true),
+            TeamHasSpaceTravel(Red, Tellar, true),
+            TeamHasSpaceTravel(Blue, Tellar, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn destroyed_spaceport_loses_controller() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            SelectGameMode(GameModeName::CaptureTheSpaceport),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            Spawn(Alice),
+            UpdateMilliseconds(10_000),
+            DestroySpaceport(Tellar, LocationName::A),
+            RebuildSpaceport(Tellar, LocationName::A),
+        ],
+        vec![
+            SpaceportController(Tellar, LocationName::A, Red),
+            Sound,
+        ]
+    )
+}
+
+pub fn respawn_at_controlled_spaceport() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::B),
+            SelectGameMode(GameModeName::CaptureTheSpaceport),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Head, [1_000_000, 1700, 0], [10000, 0, 0, 0]),
+            UpdateMilliseconds(10_000),
+            Kill(Alice),
+            Spawn(Alice),
+            UpdatePose(Alice, Tracker::Head, [0, 1700, 0], [10000, 0, 0, 0]),
+        ],
+        vec![
+            SpaceportController(Tellar, LocationName::B, Red),
+            IsAtLocation(Alice, Tellar, LocationName::B),
+            Sound,
+        ]
+    )
+}
+
+pub fn wave_arrives_after_delay() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::B),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 2, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            UpdateMilliseconds(500),
+            UpdateMilliseconds(500),
+        ],
+        vec![
+            WavesArrived(1),
+            InvadersAt(Tellar, LocationName::A, 2),
+            InvadersAt(Tellar, LocationName::B, 1),
+            InvadersAt(Tellar, LocationName::C, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn invaders_attack_cities_and_spaceports() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::B),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 2, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            UpdateMilliseconds(1000),
+            UpdateMilliseconds(2000),
+        ],
+        vec![
+            PlanetHasNumberOfPeople(Tellar, 700),
+            SpaceportHull(Tellar, LocationName::B, 8000),
+            Sound,
+        ]
+    )
+}
+
+pub fn invaders_win_when_people_killed() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::B),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 2, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            UpdateMilliseconds(1000),
+            UpdateMilliseconds(7000),
+        ],
+        vec![
+            PlanetHasNumberOfPeople(Tellar, 0),
+            MatchWinner(WinnerName::Invaders),
+            Sound,
+        ]
+    )
+}
+
+pub fn shooting_kills_invaders() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::B),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 2, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            UpdateMilliseconds(1000),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+        ],
+        vec![
+            InvadersAt(Tellar, LocationName::A, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn defenders_win_after_last_wave() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::B),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 2, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            UpdateMilliseconds(1000),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::B),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::B),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::B),
+        ],
+        vec![
+            MatchWinner(WinnerName::Defenders),
+            PlanetHasNumberOfPeople(Tellar, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn defenders_not_winning_before_last_wave() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::B),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 2, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            AddWave(5000, 1, 0),
+            UpdateMilliseconds(1000),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::B),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::B),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::B),
+        ],
+        vec![
+            MatchWinner(WinnerName::Defenders),
+            Sound,
+        ]
+    )
+}
+
+pub fn kill_count_after_killing() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            IsDead(Bob, true),
+            KillCount(Alice, 1),
+            DeathCount(Bob, 1),
+            ShotsFired(Alice, 2),
+            AccuracyPercent(Alice, 100),
+            WeaponDamage(Alice, XV43, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn missed_shots_lower_accuracy() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtNothing(Alice, Hand::Left),
+        ],
+        vec![
+            ShotsFired(Alice, 2),
+            AccuracyPercent(Alice, 50),
+            WeaponDamage(Alice, XV43, 500),
+            KillCount(Alice, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn kill_without_cause_is_not_credited() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            Kill(Bob),
+        ],
+        vec![
+            KillCount(Alice, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn deaths_counted_across_respawns() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            Spawn(Bob),
+            Kill(Bob),
+            Spawn(Bob),
+        ],
+        vec![
+            IsDead(Bob, false),
+            DeathCount(Bob, 2),
+            KillCount(Alice, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn planets_destroyed_by_player() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetWeaponChargeMilliseconds(AM0, 5000),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            CreatePlanet(Tellar),
+            CreateSpaceship(Folkum),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+            UpdateMilliseconds(5000),
+        ],
+        vec![
+            IsPlanetDestroyed(Tellar, true),
+            PlanetsDestroyed(Alice, 1),
+            PlanetsDestroyed(Bob, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn scoreboard_ranks_by_score() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            // Create two planets with two species.
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Vatrax),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Vatrax, Tellar),
+            AssignHomePlanet(Ralm, Munos),
+
+            // Create two players.
+            CreateWeapon(XV43),
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Vatrax),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            AssignWeapon(Bob, XV43, Hand::Right),
+
+            // Alice's spawning planet gets destroyed and then she gets killed.
+            DestroyPlanet(Tellar),
+            Kill(Alice),
+        ],
+        vec![
+            ScoreboardRank(Bob, 1),
+            ScoreboardRank(Alice, 2),
+            Sound,
+        ]
+    )
+}
+
+pub fn scoreboard_ranks_by_kills() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            ScoreboardRank(Alice, 1),
+            ScoreboardRank(Bob, 2),
+            Sound,
+        ]
+    )
+}
+
+pub fn killed_by_weapon() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            KilledBy(Bob, Alice, XV43),
+            KilledWithHand(Bob, Hand::Left),
+            Sound,
+        ]
+    )
+}
+
+pub fn kill_without_cause_has_no_killer() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            Kill(Bob),
+        ],
+        vec![
+            KilledBy(Bob, Alice, XV43),
+            Sound,
+        ]
+    )
+}
+
+pub fn kill_without_cause_credits_assists() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            Kill(Bob),
+        ],
+        vec![
+            Assisted(Alice, Bob),
+            AssistCount(Alice, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn assist_from_recent_damage() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            CreatePlayer(Carl),
+            AssignWeapon(Carl, XV43, Hand::Right),
+            ShootAtPlayer(Carl, Hand::Right, Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            KilledBy(Bob, Alice, XV43),
+            Assisted(Carl, Bob),
+            AssistCount(Carl, 1),
+            KillCount(Carl, 0),
+            AssistCount(Alice, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn no_assist_from_old_damage() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 500),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            CreatePlayer(Carl),
+            AssignWeapon(Carl, XV43, Hand::Right),
+            ShootAtPlayer(Carl, Hand::Right, Bob),
+            UpdateMilliseconds(11_000),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            KilledBy(Bob, Alice, XV43),
+            Assisted(Carl, Bob),
+            Sound,
+        ]
+    )
+}
+
+pub fn killed_by_canon() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 1000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            SetCanonBlastRadius(SR6, 100),
+            UpdatePose(Bob, Tracker::Head, [0, 0, 10_000], [10000, 0, 0, 0]),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+        ],
+        vec![
+            IsDead(Bob, true),
+            KilledByCanon(Bob, Alice, SR6),
+            KillCount(Alice, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn casualties_from_invaders() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::B),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 2, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            UpdateMilliseconds(1000),
+            UpdateMilliseconds(2000),
+        ],
+        vec![
+            Casualties(Tellar, Ralm, 300),
+            PeopleKilled(Alice, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_destroyer_kills_everyone() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetWeaponChargeMilliseconds(AM0, 5000),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+            CreatePlanet(Tellar),
+            CreateSpaceship(Folkum),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+            CreateSpecies(Ralm),
+            CreateSpecies(Vatrax),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            PopulateCity(Eldonar, 1000, Ralm),
+            PopulateCity(Eldonar, 500, Vatrax),
+            UpdateMilliseconds(5000),
+        ],
+        vec![
+            PlanetHasNumberOfPeople(Tellar, 0),
+            Casualties(Tellar, Ralm, 1000),
+            Casualties(Tellar, Vatrax, 500),
+            PeopleKilled(Alice, 1500),
+            WarCrimeScore(Alice, 115),
+            WarCrimeScore(Bob, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn canon_kills_people_in_city() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 1000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            PopulateCity(Eldonar, 1000, Ralm),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+        ],
+        vec![
+            PlanetHasNumberOfPeople(Tellar, 900),
+            Casualties(Tellar, Ralm, 100),
+            PeopleKilled(Alice, 100),
+            WarCrimeScore(Alice, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn destroyed_planet_casualties_without_attacker() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::B),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 2, 1),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            DestroyPlanet(Tellar),
+        ],
+        vec![
+            Casualties(Tellar, Ralm, 1000),
+            PeopleKilled(Alice, 1000),
+            Sound,
+        ]
+    )
+}
+
+pub fn population_grows_over_time() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateCity(Tarat),
+            AssignLocation(Tarat, Munos, LocationName::A),
+            CreateSpaceport(Munos, LocationName::A),
+            PopulateCity(Eldonar, 1000, Ralm),
+            SetSpeciesGrowth(Ralm, 10),
+            UpdateMilliseconds(1500),
+            UpdateMilliseconds(500),
+        ],
+        vec![
+            CityHasNumberOfPeople(Eldonar, 1020),
+            PlanetHasNumberOfPeople(Tellar, 1020),
+            Sound,
+        ]
+    )
+}
+
+pub fn population_static_without_growth() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateCity(Tarat),
+            AssignLocation(Tarat, Munos, LocationName::A),
+            CreateSpaceport(Munos, LocationName::A),
+            PopulateCity(Eldonar, 1000, Ralm),
+            UpdateMilliseconds(2000),
+        ],
+        vec![
+            PlanetHasNumberOfPeople(Tellar, 1000),
+            PlanetHasNumberOfPeople(Munos, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn people_migrate_via_spaceports() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateCity(Tarat),
+            AssignLocation(Tarat, Munos, LocationName::A),
+            CreateSpaceport(Munos, LocationName::A),
+            PopulateCity(Eldonar, 1000, Ralm),
+            SetMigrationRate(100),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            CityHasNumberOfPeople(Eldonar, 900),
+            CityHasNumberOfPeople(Tarat, 100),
+            Sound,
+        ]
+    )
+}
+
+pub fn no_migration_without_working_spaceport() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateCity(Tarat),
+            AssignLocation(Tarat, Munos, LocationName::A),
+            CreateSpaceport(Munos, LocationName::A),
+            PopulateCity(Eldonar, 1000, Ralm),
+            DestroySpaceport(Munos, LocationName::A),
+            SetMigrationRate(100),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            CityHasNumberOfPeople(Tarat, 100),
+            Sound,
+        ]
+    )
+}
+
+pub fn evacuation_under_planet_destroyer_attack() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateCity(Tarat),
+            AssignLocation(Tarat, Munos, LocationName::A),
+            CreateSpaceport(Munos, LocationName::A),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetWeaponChargeMilliseconds(AM0, 5000),
+            CreateSpaceship(Folkum),
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            CityHasNumberOfPeople(Eldonar, 800),
+            CityHasNumberOfPeople(Tarat, 200),
+            Sound,
+        ]
+    )
+}
+
+pub fn evacuees_survive_planet_destruction() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateCity(Tarat),
+            AssignLocation(Tarat, Munos, LocationName::A),
+            CreateSpaceport(Munos, LocationName::A),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetWeaponChargeMilliseconds(AM0, 5000),
+            CreateSpaceship(Folkum),
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+            UpdateMilliseconds(5000),
+        ],
+        vec![
+            IsPlanetDestroyed(Tellar, true),
+            Casualties(Tellar, Ralm, 328),
+            PlanetHasNumberOfPeople(Munos, 672),
+            Sound,
+        ]
+    )
+}
+
+pub fn shield_regenerates_over_short_frames() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+            SetShield(Bob, 300),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            UpdateMilliseconds(3000),
+            // Each frame regenerates 12.5 shield.
+            UpdateMilliseconds(250),
+            UpdateMilliseconds(250),
+            UpdateMilliseconds(250),
+            UpdateMilliseconds(250),
+        ],
+        vec![
+            HasShield(Bob, 250),
+            Sound,
+        ]
+    )
+}
+
+pub fn damage_overflowing_shield_is_not_resisted() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 200),
+            SetWeaponDamageType(TT180, DamageType::Energy),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            SetShield(Bob, 50),
+            SetShieldResistance(Bob, DamageType::Energy, 50),
+
+            // Shield covers 100 of the hit, the other 100 passes through.
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            HasShield(Bob, 0),
+            HasLife(Bob, 900),
+            Sound,
+        ]
+    )
+}
+
+pub fn life_regenerates_over_short_frames() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 100),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            CreatePlayer(Bob),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            UpdateMilliseconds(5000),
+            // Each frame regenerates 0.6 life.
+            UpdateMilliseconds(30),
+            UpdateMilliseconds(30),
+            UpdateMilliseconds(30),
+            UpdateMilliseconds(30),
+            UpdateMilliseconds(30),
+        ],
+        vec![
+            HasLife(Bob, 903),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_heal_out_of_range() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(TT180),
+            SetWeaponHealing(TT180, 200),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            SetLife(Bob, 500),
+            // Bob walks 10 meters away.
+            UpdatePose(Bob, Tracker::Head, [10000, 1700, 0], [10000, 0, 0, 0]),
+
+            Heal(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn burning_damage_over_short_frames() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 100),
+            SetWeaponStatusEffect(TT180, StatusEffect::Burning, 3000),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            // Each frame burns 0.5 life.
+            UpdateMilliseconds(10),
+            UpdateMilliseconds(10),
+            UpdateMilliseconds(10),
+            UpdateMilliseconds(10),
+        ],
+        vec![
+            HasLife(Bob, 898),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_spawn_with_loadout_over_planet_destroyer_limit() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetPlanetDestroyerLimit(1),
+            CreateLoadout(Assault),
+            SetLoadoutWeapon(Assault, AM0, Hand::Left),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            SelectLoadout(Bob, Assault),
+            Spawn(Bob),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn respawn_replaces_loadout_planet_destroyer() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetPlanetDestroyerLimit(1),
+            CreateLoadout(Assault),
+            SetLoadoutWeapon(Assault, AM0, Hand::Left),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            SelectLoadout(Alice, Assault),
+            Spawn(Alice),
+            Spawn(Alice),
+        ],
+        vec![
+            HasWeapon(Alice, AM0),
+            NumberOfPlanetDestroyers(1),
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_destroyer_charge_not_interrupted_without_damage() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            SetWeaponChargeMilliseconds(AM0, 5000),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, 0),
+            CreatePlanet(Tellar),
+            CreateSpaceship(Folkum),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Bob),
+            AssignWeapon(Bob, XV43, Hand::Right),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+            UpdateMilliseconds(2000),
+            // Bob hits Alice without doing damage.
+            ShootAtPlayer(Bob, Hand::Right, Alice),
+            UpdateMilliseconds(3000),
+        ],
+        vec![
+            IsPlanetDestroyed(Tellar, true),
+            Sound,
+        ]
+    )
+}
+
+pub fn rebuilt_spaceport_has_full_hull() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 10_000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+            RebuildSpaceport(Tellar, LocationName::A),
+        ],
+        vec![
+            HasNumberOfSpaceports(Tellar, 1),
+            SpaceportHull(Tellar, LocationName::A, DEFAULT_SPACEPORT_HULL),
+            Sound,
+        ]
+    )
+}
+
+pub fn destroyed_spaceship_kills_players_aboard() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            SetPlanetRadius(Tellar, 5000),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, DEFAULT_SPACESHIP_HULL),
+            // Folkum hovers 200 meters above the surface.
+            CreateSpaceship(Folkum),
+            SetSpaceshipPosition(Folkum, [0, 5_000_200, 0]),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            CreatePlayer(Bob),
+            BoardSpaceship(Bob, Folkum),
+            // Point at the sky.
+            UpdatePose(Alice, Tracker::Hand(Hand::Left), [0, 1000, 0], [7071, 7071, 0, 0]),
+
+            Fire(Alice, Hand::Left),
+        ],
+        vec![
+            IsSpaceshipDestroyed(Folkum, true),
+            IsDead(Bob, true),
+            Sound,
+        ]
+    )
+}
+
+pub fn no_player_aboard_destroyed_spaceship() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            SetPlanetRadius(Tellar, 5000),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, DEFAULT_SPACESHIP_HULL),
+            CreateSpaceship(Folkum),
+            SetSpaceshipPosition(Folkum, [0, 5_000_200, 0]),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            CreatePlayer(Bob),
+            BoardSpaceship(Bob, Folkum),
+            UpdatePose(Alice, Tracker::Hand(Hand::Left), [0, 1000, 0], [7071, 7071, 0, 0]),
+
+            Fire(Alice, Hand::Left),
+        ],
+        vec![
+            IsOnSpaceship(Bob, Folkum),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_shoot_invader_from_another_planet() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreatePlanet(Munos),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Munos),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 1, 0),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            UpdateMilliseconds(1000),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn shoot_invaders_from_orbit() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(XV43),
+            CreateSpaceship(Folkum),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 1, 0),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            UpdateMilliseconds(1000),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+        ],
+        vec![
+            InvadersAt(Tellar, LocationName::A, 0),
+            Sound,
+        ]
+    )
+}
+
+pub fn invader_ships_resist_energy_damage() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateWeapon(TT180),
+            SetWeaponDamageType(TT180, DamageType::Energy),
+            SelectGameMode(GameModeName::PlanetDefense(Tellar)),
+            AddWave(1000, 1, 0),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            Spawn(Alice),
+            UpdateMilliseconds(1000),
+            // Each shot deals half damage.
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+            ShootAtInvader(Alice, Hand::Left, Tellar, LocationName::A),
+        ],
+        vec![
+            InvadersAt(Tellar, LocationName::A, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn casualties_per_attacker_and_species() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Vatrax),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            PopulateCity(Eldonar, 50, Vatrax),
+            PopulateCity(Eldonar, 1000, Ralm),
+            CreateSpaceship(Folkum),
+            CreateCanon(SR6),
+            AssignCanon(Folkum, SR6, CanonSlot::BottomFront),
+            SetCanonFirepower(SR6, 1000),
+
+            CreatePlayer(Alice),
+            BoardSpaceship(Alice, Folkum),
+            CreatePlayer(Carl),
+            BoardSpaceship(Carl, Folkum),
+            FireCanon(Alice, CanonSlot::BottomFront, Tellar, LocationName::A),
+            FireCanon(Carl, CanonSlot::BottomFront, Tellar, LocationName::A),
+        ],
+        vec![
+            CasualtiesBy(Alice, Tellar, Vatrax, 50),
+            CasualtiesBy(Alice, Tellar, Ralm, 50),
+            CasualtiesBy(Carl, Tellar, Ralm, 100),
+            PeopleKilled(Carl, 100),
+            Sound,
+        ]
+    )
+}
+
+pub fn small_population_grows_over_time() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            PopulateCity(Eldonar, 10, Ralm),
+            SetSpeciesGrowth(Ralm, 50),
+            // Each tick grows the population by half a person.
+            UpdateMilliseconds(1000),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            CityHasNumberOfPeople(Eldonar, 11),
+            Sound,
+        ]
+    )
+}
+
+pub fn population_growth_saturates() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            PopulateCity(Eldonar, u64::MAX - 1, Ralm),
+            SetSpeciesGrowth(Ralm, 1000),
+            UpdateMilliseconds(1000),
+        ],
+        vec![
+            CityHasNumberOfPeople(Eldonar, u64::MAX),
+            Sound,
+        ]
+    )
+}
+
+pub fn cannot_spawn_with_loadout_edited_after_selection() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            CreateLoadout(Assault),
+            SetLoadoutWeapon(Assault, AM0, Hand::Left),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            SelectLoadout(Alice, Assault),
+            SetLoadoutWeapon(Assault, AM0, Hand::Right),
+            Spawn(Alice),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn loadout_with_two_planet_destroyers_is_reported_invalid() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            CreateLoadout(Assault),
+            SetLoadoutWeapon(Assault, AM0, Hand::Left),
+            SetLoadoutWeapon(Assault, AM0, Hand::Right),
+        ],
+        vec![
+            LoadoutValid(Assault, false),
+            Sound,
+        ]
+    )
+}
+
+pub fn fire_planet_destroyer_requires_spaceship() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            RequireSpaceshipForPlanetDestroyer(true),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            Spawn(Alice),
+            // Point at the ground.
+            UpdatePose(Alice, Tracker::Hand(Hand::Left), [0, 1000, 0], [7071, -7071, 0, 0]),
+
+            Fire(Alice, Hand::Left),
+        ],
+        vec![
+            IsPlanetDestroyed(Tellar, true),
+            Sound,
+        ]
+    )
+}
+
+pub fn planet_destroyer_requires_spaceship_in_orbit() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+            RequireSpaceshipForPlanetDestroyer(true),
+            CreatePlanet(Tellar),
+            CreateSpaceship(Folkum),
+            SetSpaceshipPosition(Folkum, [0, 100_000_000, 0]),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            BoardSpaceship(Alice, Folkum),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+        ],
+        vec![
+            Sound,
+        ]
+    )
+}
+
+pub fn no_spaceport_captures_in_team_match() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateSpaceport(Tellar, LocationName::A),
+            SelectGameMode(GameModeName::TeamMatch),
+            CreateTeam(Red),
+            CreateTeam(Blue),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignTeam(Alice, Red),
+            Spawn(Alice),
+            UpdateMilliseconds(10_000),
+        ],
+        vec![
+            IsAtLocation(Alice, Tellar, LocationName::A),
+            SpaceportController(Tellar, LocationName::A, Red),
+            Sound,
+        ]
+    )
+}
+
+pub fn healing_counts_as_hit() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(TT180),
+            SetWeaponHealing(TT180, 200),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            Spawn(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+            AssignSpecies(Bob, Ralm),
+            Spawn(Bob),
+            SetLife(Bob, 500),
+
+            Heal(Alice, Hand::Left, Bob),
+        ],
+        vec![
+            AccuracyPercent(Alice, 100),
+            Sound,
+        ]
+    )
+}
+
+pub fn burning_to_death_credits_attacker() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreateWeapon(TT180),
+            SetWeaponFirepower(TT180, 100),
+            SetWeaponStatusEffect(TT180, StatusEffect::Burning, 30_000),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, TT180, Hand::Left),
+            CreatePlayer(Bob),
+
+            ShootAtPlayer(Alice, Hand::Left, Bob),
+            UpdateMilliseconds(30_000),
+        ],
+        vec![
+            IsDead(Bob, true),
+            KilledBy(Bob, Alice, TT180),
+            KillCount(Alice, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn destroyed_spaceship_credits_attacker() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            SetPlanetRadius(Tellar, 5000),
+            CreateSpecies(Ralm),
+            AssignHomePlanet(Ralm, Tellar),
+            CreateWeapon(XV43),
+            SetWeaponFirepower(XV43, DEFAULT_SPACESHIP_HULL),
+            // Folkum hovers 200 meters above the surface.
+            CreateSpaceship(Folkum),
+            SetSpaceshipPosition(Folkum, [0, 5_000_200, 0]),
+
+            CreatePlayer(Alice),
+            AssignSpecies(Alice, Ralm),
+            AssignWeapon(Alice, XV43, Hand::Left),
+            Spawn(Alice),
+            CreatePlayer(Bob),
+            BoardSpaceship(Bob, Folkum),
+            // Point at the sky.
+            UpdatePose(Alice, Tracker::Hand(Hand::Left), [0, 1000, 0], [7071, 7071, 0, 0]),
+
+            Fire(Alice, Hand::Left),
+        ],
+        vec![
+            IsDead(Bob, true),
+            KilledBy(Bob, Alice, XV43),
+            KillCount(Alice, 1),
+            Sound,
+        ]
+    )
+}
+
+pub fn population_sum_saturates() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Vatrax),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            PopulateCity(Eldonar, u64::MAX, Vatrax),
+            PopulateCity(Eldonar, u64::MAX, Ralm),
+        ],
+        vec![
+            CityHasNumberOfPeople(Eldonar, u64::MAX),
+            PlanetHasNumberOfPeople(Tellar, u64::MAX),
+            Sound,
+        ]
+    )
+}
+
+pub fn people_killed_saturates() -> (Vec<Expr>, Vec<Expr>) {
+    (
+        vec![
+            CreatePlanet(Tellar),
+            CreateSpecies(Vatrax),
+            CreateSpecies(Ralm),
+            CreateCity(Eldonar),
+            AssignLocation(Eldonar, Tellar, LocationName::A),
+            PopulateCity(Eldonar, u64::MAX, Vatrax),
+            PopulateCity(Eldonar, u64::MAX, Ralm),
+            CreateWeapon(AM0),
+            SetWeaponPlanetDestroyer(AM0, true),
+
+            CreatePlayer(Alice),
+            AssignWeapon(Alice, AM0, Hand::Left),
+            ShootAtPlanet(Alice, Hand::Left, Tellar),
+        ],
+        vec![
+            IsPlanetDestroyed(Tellar, true),
+            PeopleKilled(Alice, u64::MAX),
+            Sound,
+        ]
+    )
+}
+
+/// A test returns a start story and a goal.
+pub type Test = fn() -> (Vec<Expr>, Vec<Expr>);
+
 /// Checks a list of tests.
-pub fn check(fs: &[(fn() -> (Vec<Expr>, Vec<Expr>), bool)]) {
+pub fn check(fs: &[(Test, bool)]) {
     for (i, &(f, ok)) in fs.iter().enumerate() {
         let (start, goal) = f();
         let order_constraints = vec![];
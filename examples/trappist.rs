@@ -1,4 +1,6 @@
 extern crate monotonic_solver;
+extern crate vecmath;
+extern crate quaternion;
 
 use monotonic_solver::{solve, solve_and_reduce};
 
@@ -12,8 +14,13 @@ use WeaponName::*;
 use PlayerName::*;
 use SpaceshipName::*;
 use CanonName::*;
+use LoadoutName::*;
+use TeamName::*;
 use world::*;
 use state::*;
+use rng::*;
+use game_mode::*;
+use stats::*;
 use names::*;
 use inference::infer;
 
@@ -21,6 +28,10 @@ mod world;
 mod state;
 mod names;
 mod inference;
+mod geometry;
+mod rng;
+mod game_mode;
+mod stats;
 pub mod test;
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -57,6 +68,13 @@ pub enum Expr {
     AssignCanon(SpaceshipName, CanonName, CanonSlot),
     /// Spawn player.
     Spawn(PlayerName),
+    /// Player boards spaceship.
+    BoardSpaceship(PlayerName, SpaceshipName),
+    /// Updates the pose of a tracked device,
+    /// relative to the planet surface or spaceship the player is on.
+    /// The position is in millimeters.
+    /// The orientation is a quaternion `[w, x, y, z]` scaled by 10 000.
+    UpdatePose(PlayerName, Tracker, [i32; 3], [i16; 4]),
     /// Destroy spaceport.
     DestroySpaceport(PlanetName, LocationName),
     /// Destroy planet.
@@ -65,6 +83,10 @@ pub enum Expr {
     RebuildSpaceport(PlanetName, LocationName),
     /// Populates city with a number of people.
     PopulateCity(CityName, u64, SpeciesName),
+    /// Set population growth of species per mille per second.
+    SetSpeciesGrowth(SpeciesName, u32),
+    /// Set people migrating per mille per second between cities with working spaceports.
+    SetMigrationRate(u32),
     /// Drops player's weapon by hand.
     DropWeapon(PlayerName, Hand),
     /// Player shoots at planet.
@@ -73,12 +95,16 @@ pub enum Expr {
     ShootAtPlayer(PlayerName, Hand, PlayerName),
     /// Shoot at nothing.
     ShootAtNothing(PlayerName, Hand),
+    /// Fire weapon in the direction the hand is pointing.
+    Fire(PlayerName, Hand),
     /// Kills player.
     Kill(PlayerName),
     /// Recharges weapon with an amount of milliseconds.
     RechargeMilliseconds(PlayerName, Hand, u16),
     /// Recharges all weapons with an amount of milliseconds.
     RechargeMillisecondsAllWeapons(u16),
+    /// Advances the simulation clock with an amount of milliseconds.
+    UpdateMilliseconds(u16),
     /// Set player life.
     SetLife(PlayerName, u16),
     /// Set weapon firepower.
@@ -87,8 +113,121 @@ pub enum Expr {
     SetWeaponRechargeMilliseconds(WeaponName, u16),
     /// Set weapon to be planet destroyer.
     SetWeaponPlanetDestroyer(WeaponName, bool),
+    /// Set weapon projectile speed in meters per second.
+    /// A speed of zero hits instantly.
+    SetWeaponProjectileSpeed(WeaponName, u32),
+    /// Set weapon projectile lifetime in milliseconds.
+    SetWeaponProjectileLifetime(WeaponName, u32),
+    /// Set weapon spread in milliradians.
+    SetWeaponSpread(WeaponName, u16),
+    /// Set weapon chance in percent to hit when shooting at player.
+    SetWeaponAccuracy(WeaponName, u8),
+    /// Set the maximum deviation from weapon firepower per hit.
+    SetWeaponDamageVariance(WeaponName, u16),
+    /// Seeds the random number generator.
+    SetSeed(u64),
+    /// Set weapon damage type.
+    SetWeaponDamageType(WeaponName, DamageType),
+    /// Set player shield capacity and fill it up.
+    SetShield(PlayerName, u16),
+    /// Set player shield resistance in percent against a damage type.
+    SetShieldResistance(PlayerName, DamageType, u8),
+    /// Set player armor.
+    SetArmor(PlayerName, u16),
+    /// Set player armor resistance in percent against a damage type.
+    SetArmorResistance(PlayerName, DamageType, u8),
+    /// Set the maximum life that players can heal up to.
+    SetMaxLife(u16),
+    /// Set how much life weapon heals, making it a healing tool.
+    SetWeaponHealing(WeaponName, u16),
+    /// Put a number of medkits in city.
+    StockMedkits(CityName, u16),
+    /// Player picks up medkit at city location.
+    PickUpMedkit(PlayerName, CityName),
+    /// Player heals teammate with healing tool.
+    Heal(PlayerName, Hand, PlayerName),
+    /// Set status effect applied by weapon and its duration in milliseconds.
+    SetWeaponStatusEffect(WeaponName, StatusEffect, u32),
+    /// Set weapon magazine or energy cell capacity in rounds.
+    SetWeaponMagazine(WeaponName, u16),
+    /// Set weapon reload time in milliseconds.
+    SetWeaponReloadMilliseconds(WeaponName, u16),
+    /// Set the rounds player carries for reloading.
+    SetSpareAmmo(PlayerName, u16),
+    /// Set the number of holster slots of player.
+    SetHolsterSlots(PlayerName, u8),
+    /// Assign a weapon to player's holster slot.
+    AssignHolster(PlayerName, WeaponName, u8),
+    /// Player moves weapon from hand to holster slot.
+    Holster(PlayerName, Hand, u8),
+    /// Player draws weapon from holster slot into hand.
+    Draw(PlayerName, Hand, u8),
+    /// Open the lobby for a new match.
+    OpenLobby,
+    /// Start the match from the lobby.
+    StartMatch,
+    /// Set the duration in milliseconds of a timed match phase.
+    SetPhaseDuration(Phase, u32),
+    /// Add a wave of invader ships and boarders arriving
+    /// some milliseconds after the previous wave.
+    AddWave(u32, u8, u8),
+    /// Shoot at an invader at a location on planet.
+    ShootAtInvader(PlayerName, Hand, PlanetName, LocationName),
+    /// Set how long a team must hold a spaceport alone to capture it.
+    SetCaptureMilliseconds(u32),
+    /// Select the game mode of the match.
+    SelectGameMode(GameModeName),
+    /// Create new team.
+    CreateTeam(TeamName),
+    /// Assign player to team.
+    AssignTeam(PlayerName, TeamName),
+    /// Make two teams allies.
+    AllyTeams(TeamName, TeamName),
+    /// Set how damage between teammates is treated.
+    SetFriendlyFire(FriendlyFire),
+    /// Set whether players can damage themselves.
+    SetSelfDamage(bool),
+    /// Set weapon blast radius in meters.
+    SetWeaponBlastRadius(WeaponName, u32),
+    /// Set canon blast radius in meters.
+    SetCanonBlastRadius(CanonName, u32),
+    /// Player aboard spaceship fires canon at planet location.
+    FireCanon(PlayerName, CanonSlot, PlanetName, LocationName),
+    /// Set how long weapon charges before a planet destroyer strike.
+    SetWeaponChargeMilliseconds(WeaponName, u32),
+    /// Limit the number of planet destroyers in play.
+    SetPlanetDestroyerLimit(u16),
+    /// Require players to be aboard a spaceship to fire planet destroyers.
+    RequireSpaceshipForPlanetDestroyer(bool),
+    /// Create new loadout.
+    CreateLoadout(LoadoutName),
+    /// Put a weapon in loadout hand.
+    SetLoadoutWeapon(LoadoutName, WeaponName, Hand),
+    /// Put a weapon in loadout holster slot.
+    SetLoadoutHolster(LoadoutName, WeaponName, u8),
+    /// Restrict loadout to players of a species.
+    RestrictLoadout(LoadoutName, SpeciesName),
+    /// Player selects loadout to be applied when spawning.
+    SelectLoadout(PlayerName, LoadoutName),
+    /// Player picks up a weapon lying nearby with empty hand.
+    PickUpWeapon(PlayerName, Hand),
+    /// Player reloads weapon in hand from spare ammo.
+    Reload(PlayerName, Hand),
+    /// Put a number of ammo packs in spaceport.
+    StockAmmo(PlanetName, LocationName, u16),
+    /// Player picks up ammo pack at spaceport location.
+    PickUpAmmo(PlayerName, PlanetName, LocationName),
+    /// Set damage multiplier in percent for hit zone.
+    /// Both hands share the same multiplier.
+    SetHitZoneDamage(HitZone, u16),
     /// Set canon firepower.
     SetCanonFirepower(CanonName, u16),
+    /// Set planet position in kilometers.
+    SetPlanetPosition(PlanetName, [i32; 3]),
+    /// Set planet radius in kilometers.
+    SetPlanetRadius(PlanetName, u32),
+    /// Set spaceship position in meters.
+    SetSpaceshipPosition(SpaceshipName, [i32; 3]),
     /// The story works out.
     Sound,
     /// The world contains planets.
@@ -133,10 +272,6 @@ pub enum Expr {
     OutOfGame(PlayerName, bool),
     /// The number of players left.
     NumberOfPlayersLeft(usize),
-    /// Which player won death match.
-    DeathMatchWinner(PlayerName),
-    /// Which species won death match.
-    TeamMatchWinner(SpeciesName),
     /// The number of users per weapon.
     NumberOfWeaponUsers(WeaponName, usize),
     /// Whether planet is destroyed.
@@ -151,6 +286,111 @@ pub enum Expr {
     CanShoot(PlayerName, Hand, bool),
     /// Which planet player is currently on.
     IsOnPlanet(PlayerName, PlanetName),
+    /// Which spaceship player is currently on.
+    IsOnSpaceship(PlayerName, SpaceshipName),
+    /// The pose of a tracked device, in the same units as `UpdatePose`.
+    HasPose(PlayerName, Tracker, [i32; 3], [i16; 4]),
+    /// The distance in meters between the heads of two players.
+    DistanceBetween(PlayerName, PlayerName, u32),
+    /// What the last shot fired by player's hand hit.
+    LastHit(PlayerName, Hand, TargetName),
+    /// The number of projectiles in flight.
+    NumberOfProjectiles(usize),
+    /// Where player was last hit.
+    LastHitZone(PlayerName, HitZone),
+    /// How much shield a player has.
+    HasShield(PlayerName, u16),
+    /// How much armor a player has.
+    HasArmor(PlayerName, u16),
+    /// The number of medkits available in city.
+    HasMedkits(CityName, u16),
+    /// A city has number of people.
+    CityHasNumberOfPeople(CityName, u64),
+    /// A status effect on player and its remaining milliseconds.
+    HasStatus(PlayerName, StatusEffect, u32),
+    /// Rounds left in the weapon in player's hand.
+    AmmoLeft(PlayerName, Hand, u16),
+    /// Rounds player carries for reloading.
+    SpareAmmo(PlayerName, u16),
+    /// A weapon is in player's holster slot.
+    Holstered(PlayerName, u8, WeaponName),
+    /// Player is charging a planet destroyer at planet,
+    /// with remaining milliseconds.
+    Charging(PlayerName, PlanetName, u32),
+    /// All players were told that player started charging a planet destroyer at planet.
+    PlanetDestroyerAnnounced(PlayerName, PlanetName),
+    /// All players were told that a planet destroyer charge was interrupted.
+    PlanetDestroyerInterrupted(PlayerName, PlanetName),
+    /// Player is on team.
+    IsOnTeam(PlayerName, TeamName),
+    /// Two teams are allies.
+    AreAllies(TeamName, TeamName),
+    /// The current phase of the match.
+    MatchPhase(Phase),
+    /// Time left of the current match phase.
+    PhaseMillisecondsLeft(u32),
+    /// The winner of the match decided by the active game mode.
+    MatchWinner(WinnerName),
+    /// The score of player decided by the active game mode.
+    Score(PlayerName, u32),
+    /// The number of other players killed by player.
+    KillCount(PlayerName, u32),
+    /// The number of times player died.
+    DeathCount(PlayerName, u32),
+    /// The number of shots fired by player.
+    ShotsFired(PlayerName, u32),
+    /// The percentage of shots fired by player that hit.
+    AccuracyPercent(PlayerName, u32),
+    /// The life player took from other players with a weapon.
+    WeaponDamage(PlayerName, WeaponName, u32),
+    /// The number of planets destroyed by player.
+    PlanetsDestroyed(PlayerName, u32),
+    /// The number of deaths of other players that player assisted in.
+    AssistCount(PlayerName, u32),
+    /// A player was last killed by another player with a weapon.
+    KilledBy(PlayerName, PlayerName, WeaponName),
+    /// A player was last killed by another player with a spaceship canon.
+    KilledByCanon(PlayerName, PlayerName, CanonName),
+    /// A player was last killed by a weapon held in a hand.
+    KilledWithHand(PlayerName, Hand),
+    /// A player assisted in the last death of another player.
+    Assisted(PlayerName, PlayerName),
+    /// The number of people of a species killed on planet.
+    Casualties(PlanetName, SpeciesName, u64),
+    /// The number of people killed by player.
+    PeopleKilled(PlayerName, u64),
+    /// The number of people of a species killed on planet by player.
+    CasualtiesBy(PlayerName, PlanetName, SpeciesName, u64),
+    /// The war crime score of player.
+    WarCrimeScore(PlayerName, u32),
+    /// The rank of player in the scoreboard, starting at 1.
+    ScoreboardRank(PlayerName, usize),
+    /// An explosion damaged target.
+    BlastHit(TargetName, u16),
+    /// The hull of spaceship.
+    HasHull(SpaceshipName, u16),
+    /// Whether spaceship is destroyed.
+    IsSpaceshipDestroyed(SpaceshipName, bool),
+    /// The hull of spaceport.
+    SpaceportHull(PlanetName, LocationName, u16),
+    /// The number of waves of invaders that have arrived.
+    WavesArrived(usize),
+    /// The number of invaders at a location on planet.
+    InvadersAt(PlanetName, LocationName, usize),
+    /// The team controlling a spaceport.
+    SpaceportController(PlanetName, LocationName, TeamName),
+    /// A team controls a working spaceport on planet.
+    TeamHasSpaceTravel(TeamName, PlanetName, bool),
+    /// A player is at a location on planet.
+    IsAtLocation(PlayerName, PlanetName, LocationName),
+    /// The number of planet destroyers carried or lying around.
+    NumberOfPlanetDestroyers(usize),
+    /// Whether loadout respects weapon restrictions.
+    LoadoutValid(LoadoutName, bool),
+    /// Player has selected loadout.
+    HasLoadout(PlayerName, LoadoutName),
+    /// A dropped weapon lies on planet.
+    WeaponLyingOn(WeaponName, PlanetName),
 }
 
 pub fn test() -> (Vec<Expr>, Vec<Expr>) {
@@ -219,6 +459,180 @@ fn main() {
             (test::can_shoot_when_weapon_is_recharged, true),
             // 40
             (test::recharge_milliseconds_all_weapons, true),
+            (test::update_head_pose, true),
+            (test::distance_between_players_on_same_planet, true),
+            (test::board_spaceship, true),
+            (test::fire_at_player, true),
+            (test::fire_at_planet, true),
+            (test::fire_at_spaceship, true),
+            (test::fire_at_nothing, true),
+            (test::projectile_in_flight, true),
+            (test::projectile_hits_player, true),
+            // 50
+            (test::dodge_projectile, true),
+            (test::projectile_hits_planet, true),
+            (test::miss_with_zero_accuracy, true),
+            (test::damage_variance_with_pinned_seed, true),
+            (test::spread_with_pinned_seed, true),
+            (test::headshot, true),
+            (test::hand_hit_drops_weapon, true),
+            (test::lethal_headshot_rule, true),
+            (test::shield_absorbs_damage, true),
+            (test::damage_through_shield_armor_and_life, true),
+            // 60
+            (test::shield_resists_energy_damage, true),
+            (test::shield_regenerates, true),
+            (test::life_regenerates, true),
+            (test::life_regeneration_is_capped, true),
+            (test::pick_up_medkit, true),
+            (test::cannot_pick_up_medkit_far_from_city, false),
+            (test::heal_teammate, true),
+            (test::cannot_heal_enemy, false),
+            (test::stun_blocks_shooting, true),
+            (test::stun_wears_off, true),
+            // 70
+            (test::burning_damage, true),
+            (test::emp_blocks_recharge, true),
+            (test::slowed_recharge, true),
+            (test::magazine_runs_empty, true),
+            (test::reload_from_spare_ammo, true),
+            (test::reload_takes_time, true),
+            (test::pick_up_ammo_at_spaceport, true),
+            (test::cannot_pick_up_ammo_at_destroyed_spaceport, false),
+            (test::weapon_items_have_separate_ammo, true),
+            (test::dropping_weapon_item_keeps_other_users, true),
+            // 80
+            (test::dropped_weapon_lies_on_planet, true),
+            (test::pick_up_dropped_weapon, true),
+            (test::cannot_pick_up_weapon_out_of_reach, false),
+            (test::cannot_pick_up_despawned_weapon, false),
+            (test::holster_weapon, true),
+            (test::draw_weapon_takes_swap_time, true),
+            (test::cannot_holster_in_missing_slot, false),
+            (test::extra_holster_slot, true),
+            (test::holstered_weapons_count_as_weapons, true),
+            (test::spawn_with_loadout, true),
+            // 90
+            (test::respawn_with_fresh_loadout, true),
+            (test::loadout_restricted_to_species, false),
+            (test::loadout_allowed_for_species, true),
+            (test::loadout_with_two_planet_destroyers_is_invalid, false),
+            (test::planet_destroyer_limit, false),
+            (test::planet_destroyer_charges_before_strike, true),
+            (test::planet_destroyer_charge_completes, true),
+            (test::planet_destroyer_charge_interrupted_by_damage, true),
+            (test::planet_destroyer_requires_spaceship, false),
+            (test::planet_destroyer_aboard_spaceship, true),
+            // 100
+            (test::canon_hits_spaceport, true),
+            (test::canon_blast_damages_players_and_spaceports, true),
+            (test::blast_falls_off_with_distance, true),
+            (test::cannot_fire_canon_without_boarding, false),
+            (test::spaceport_destroyed_by_canon, true),
+            (test::explosive_hand_weapon, true),
+            (test::friendly_fire_full_by_default, true),
+            (test::friendly_fire_off, true),
+            (test::friendly_fire_reduced, true),
+            (test::friendly_fire_off_still_damages_enemies, true),
+            // 110
+            (test::self_damage_off, true),
+            (test::self_damage_on_by_default, true),
+            (test::friendly_fire_off_protects_from_blast, true),
+            (test::same_species_on_opposing_teams, true),
+            (test::different_species_on_same_team, true),
+            (test::allied_teams_are_friendly, true),
+            (test::winning_team, true),
+            (test::allied_teams_win_together, true),
+            (test::no_winning_team_while_enemies_remain, false),
+            (test::death_match_is_default_game_mode, true),
+            // 120
+            (test::planet_defense_lost_when_planet_destroyed, true),
+            (test::planet_defense_not_lost_while_people_live, false),
+            (test::capture_the_spaceport_winner, true),
+            (test::cannot_respawn_without_spawning_planet, false),
+            (test::respawn_revives_player, true),
+            (test::team_match_score, true),
+            (test::lobby_phase, true),
+            (test::cannot_shoot_in_lobby, false),
+            (test::cannot_spawn_in_lobby, false),
+            (test::warmup_then_countdown, true),
+            // 130
+            (test::cannot_shoot_during_countdown, false),
+            (test::match_in_progress_after_countdown, true),
+            (test::overtime_without_winner, true),
+            (test::match_ends_after_overtime, true),
+            (test::match_ends_when_winner_decided, true),
+            (test::no_damage_after_match_ended, false),
+            (test::cannot_change_team_after_lobby, false),
+            (test::spaceport_not_captured_before_hold_duration, false),
+            (test::contested_spaceport_not_captured, false),
+            (test::capture_progress_lost_when_leaving, false),
+            // 140
+            (test::controlled_spaceport_scores_over_time, true),
+            (test::team_has_space_travel_with_controlled_spaceport, true),
+            (test::destroyed_spaceport_loses_controller, false),
+            (test::respawn_at_controlled_spaceport, true),
+            (test::wave_arrives_after_delay, true),
+            (test::invaders_attack_cities_and_spaceports, true),
+            (test::invaders_win_when_people_killed, true),
+            (test::shooting_kills_invaders, true),
+            (test::defenders_win_after_last_wave, true),
+            (test::defenders_not_winning_before_last_wave, false),
+            // 150
+            (test::kill_count_after_killing, true),
+            (test::missed_shots_lower_accuracy, true),
+            (test::kill_without_cause_is_not_credited, false),
+            (test::deaths_counted_across_respawns, true),
+            (test::planets_destroyed_by_player, true),
+            (test::scoreboard_ranks_by_score, true),
+            (test::scoreboard_ranks_by_kills, true),
+            (test::killed_by_weapon, true),
+            (test::kill_without_cause_has_no_killer, false),
+            (test::kill_without_cause_credits_assists, true),
+            // 160
+            (test::assist_from_recent_damage, true),
+            (test::no_assist_from_old_damage, false),
+            (test::killed_by_canon, true),
+            (test::casualties_from_invaders, true),
+            (test::planet_destroyer_kills_everyone, true),
+            (test::canon_kills_people_in_city, true),
+            (test::destroyed_planet_casualties_without_attacker, false),
+            (test::population_grows_over_time, true),
+            (test::population_static_without_growth, true),
+            (test::people_migrate_via_spaceports, true),
+            // 170
+            (test::no_migration_without_working_spaceport, false),
+            (test::evacuation_under_planet_destroyer_attack, true),
+            (test::evacuees_survive_planet_destruction, true),
+            (test::shield_regenerates_over_short_frames, true),
+            (test::damage_overflowing_shield_is_not_resisted, true),
+            (test::life_regenerates_over_short_frames, true),
+            (test::cannot_heal_out_of_range, false),
+            (test::burning_damage_over_short_frames, true),
+            (test::cannot_spawn_with_loadout_over_planet_destroyer_limit, false),
+            (test::respawn_replaces_loadout_planet_destroyer, true),
+            // 180
+            (test::planet_destroyer_charge_not_interrupted_without_damage, true),
+            (test::rebuilt_spaceport_has_full_hull, true),
+            (test::destroyed_spaceship_kills_players_aboard, true),
+            (test::no_player_aboard_destroyed_spaceship, false),
+            (test::cannot_shoot_invader_from_another_planet, false),
+            (test::shoot_invaders_from_orbit, true),
+            (test::invader_ships_resist_energy_damage, true),
+            (test::casualties_per_attacker_and_species, true),
+            (test::small_population_grows_over_time, true),
+            (test::population_growth_saturates, true),
+            // 190
+            (test::cannot_spawn_with_loadout_edited_after_selection, false),
+            (test::loadout_with_two_planet_destroyers_is_reported_invalid, true),
+            (test::fire_planet_destroyer_requires_spaceship, false),
+            (test::planet_destroyer_requires_spaceship_in_orbit, false),
+            (test::no_spaceport_captures_in_team_match, false),
+            (test::healing_counts_as_hit, true),
+            (test::burning_to_death_credits_attacker, true),
+            (test::destroyed_spaceship_credits_attacker, true),
+            (test::population_sum_saturates, true),
+            (test::people_killed_saturates, true),
         ]);
 
     let (start, goal) = test();
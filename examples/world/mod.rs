@@ -1,12 +1,34 @@
+use vecmath::{vec3_add, vec3_cast, vec3_cross, vec3_len, vec3_normalized, vec3_scale, vec3_sub};
+use quaternion::{self, Quaternion};
+use geometry::{ray_capsule, ray_sphere};
+use rng::{Rng, DEFAULT_SEED};
+use game_mode::{DeathMatch, GameMode};
+use stats::{Attack, Cause, Death, Stats, ASSIST_MILLISECONDS};
+
 const LOCATIONS_PER_PLANET: usize = 4;
 const SPECIES: usize = 3;
+const DAMAGE_TYPES: usize = 3;
+const STATUS_EFFECTS: usize = 4;
+
+/// The distance in meters between neighbour locations on a planet surface.
+pub const LOCATION_SPACING: f64 = 1000.0;
+/// How close in meters a player must be to use a location.
+pub const LOCATION_RADIUS: f64 = 100.0;
+
+/// The default planet radius in meters.
+pub const DEFAULT_PLANET_RADIUS: f64 = 6_000_000.0;
 
 pub struct Planet {
-    pub pos: [f32; 3],
+    /// Position in meters.
+    pub pos: [f64; 3],
+    /// Radius in meters.
+    pub radius: f64,
     pub orbit: Option<usize>,
     pub cities: [Option<usize>; LOCATIONS_PER_PLANET],
     pub spaceports: [Option<usize>; LOCATIONS_PER_PLANET],
     pub destroyed: bool,
+    /// People killed on the planet per species.
+    pub casualties: [u64; SPECIES],
 }
 
 impl Planet {
@@ -23,41 +45,301 @@ impl Planet {
     }
 
     pub fn population(&self, world: &World) -> u64 {
-        let mut sum = 0;
+        let mut sum: u64 = 0;
         for city in &self.cities {
             if let Some(city_id) = *city {
                 for &population in &world.cities[city_id].population {
-                    sum += population
+                    sum = sum.saturating_add(population)
                 }
             }
         }
         sum
     }
+
+    /// Returns the origin of the planet surface frame.
+    /// The Y axis points up from the surface.
+    pub fn surface_origin(&self) -> [f64; 3] {
+        vec3_add(self.pos, [0.0, self.radius, 0.0])
+    }
+
+    /// Returns the position of a location on the planet surface.
+    pub fn location_pos(&self, location: u8) -> [f64; 3] {
+        let offset = match location {
+            0 => [0.0, 0.0, 0.0],
+            1 => [LOCATION_SPACING, 0.0, 0.0],
+            2 => [0.0, 0.0, LOCATION_SPACING],
+            _ => [LOCATION_SPACING, 0.0, LOCATION_SPACING],
+        };
+        vec3_add(self.surface_origin(), offset)
+    }
 }
 
 pub struct Orbit;
 
 pub struct Species {
     pub home_planet: Option<usize>,
+    /// Population growth per mille per second.
+    pub growth_per_mille: u32,
+}
+
+/// How often population grows, migrates and evacuates.
+pub const POPULATION_TICK_MILLISECONDS: u32 = 1000;
+/// People evacuating per mille per second from a planet under attack.
+pub const EVACUATION_PER_MILLE: u64 = 200;
+
+pub struct Team {
+    /// Teams that this team is allied with.
+    pub allies: Vec<usize>,
+    /// Points scored by holding spaceports.
+    pub points: u32,
+    /// Time held towards the next point.
+    pub point_milliseconds: u32,
+}
+
+/// The side a player fights for.
+/// Players on a team fight for their team,
+/// other players fight for their species.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Side {
+    Team(usize),
+    Species(usize),
 }
 
 pub struct City {
     pub planet: Option<usize>,
     pub location: Option<u8>,
     pub population: [u64; SPECIES],
+    /// Population growth in thousandths of a person not yet born, per species.
+    pub growth_remainder: [u64; SPECIES],
+    /// Number of medkits available for pickup.
+    pub medkits: u16,
 }
 
+/// How much life a medkit heals.
+pub const MEDKIT_LIFE: u16 = 500;
+/// How far in meters a healing tool reaches.
+pub const HEALING_RANGE: f64 = 5.0;
+
+/// How many rounds an ammo pack contains.
+pub const AMMO_PACK_ROUNDS: u16 = 30;
+
+pub const DEFAULT_SPACEPORT_HULL: u16 = 10_000;
+
 pub struct Spaceport {
     pub destroyed: bool,
+    /// The spaceport is destroyed when its hull reaches zero.
+    pub hull: u16,
+    /// Number of ammo packs available for pickup.
+    pub ammo_packs: u16,
+    /// The team that captured the spaceport.
+    pub owner: Option<usize>,
+    /// The team currently capturing the spaceport.
+    pub capturing: Option<usize>,
+    /// How long the capturing team has held the spaceport.
+    pub capture_milliseconds: u32,
+}
+
+/// Default time a team must hold a spaceport alone to capture it.
+pub const DEFAULT_CAPTURE_MILLISECONDS: u32 = 10_000;
+/// Time a captured spaceport must be held to give its team a point.
+pub const SPACEPORT_POINT_MILLISECONDS: u32 = 1000;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DamageType {
+    Kinetic = 0,
+    Energy = 1,
+    AntiMatter = 2,
 }
 
 pub const DEFAULT_WEAPON_FIREPOWER: u16 = 1000;
 pub const DEFAULT_WEAPON_RECHARGE_MILLISECONDS: u16 = 100;
+pub const DEFAULT_PROJECTILE_LIFETIME_MILLISECONDS: u32 = 10_000;
+pub const DEFAULT_WEAPON_RELOAD_MILLISECONDS: u16 = 2000;
 
 pub struct Weapon {
     pub firepower: u16,
+    pub damage_type: DamageType,
     pub recharge_milliseconds: u16,
     pub planet_destroyer: bool,
+    /// How much life the weapon heals teammates, if it is a healing tool.
+    pub healing: u16,
+    /// Projectile speed in meters per second.
+    /// Weapons without projectile speed hit instantly.
+    pub projectile_speed: Option<f64>,
+    /// How long projectiles fly before they disappear.
+    pub projectile_lifetime_milliseconds: u32,
+    /// The maximum angle in milliradians that shots deviate from the aim.
+    pub spread_milliradians: u16,
+    /// The chance in percent to hit when shooting at a player.
+    pub accuracy_percent: u8,
+    /// The maximum deviation from firepower per hit.
+    pub damage_variance: u16,
+    /// Status effect applied to players hit and its duration in milliseconds.
+    pub status_effect: Option<(StatusEffect, u32)>,
+    /// Magazine or energy cell capacity in rounds.
+    /// Weapons without magazine never run out of ammo.
+    pub magazine: Option<u16>,
+    /// How long it takes to reload.
+    pub reload_milliseconds: u16,
+    /// How long a planet destroyer charges before it hits.
+    pub charge_milliseconds: u32,
+    /// Explosions damage everything within this radius in meters.
+    pub blast_radius: f64,
+}
+
+/// A kind of AI-controlled invader attacking a defended planet.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum InvaderKind {
+    /// Bombards spaceports and cities from orbit.
+    Ship,
+    /// Lands in cities and kills people.
+    Boarder,
+}
+
+impl InvaderKind {
+    /// Returns the resistance in percent per damage type.
+    pub fn resistance_percent(self) -> [u8; DAMAGE_TYPES] {
+        match self {
+            InvaderKind::Ship => INVADER_SHIP_RESISTANCE_PERCENT,
+            InvaderKind::Boarder => INVADER_BOARDER_RESISTANCE_PERCENT,
+        }
+    }
+}
+
+/// A scripted wave of invaders attacking a defended planet.
+#[derive(Copy, Clone, Debug)]
+pub struct Wave {
+    /// Time after the previous wave before this wave arrives.
+    pub delay_milliseconds: u32,
+    pub ships: u8,
+    pub boarders: u8,
+}
+
+/// An AI-controlled invader attacking a location on a planet.
+#[derive(Copy, Clone, Debug)]
+pub struct Invader {
+    pub kind: InvaderKind,
+    pub planet: usize,
+    pub location: u8,
+    /// The invader is killed when its life reaches zero.
+    pub life: u16,
+    /// Time since the last attack.
+    pub attack_milliseconds: u32,
+}
+
+pub const INVADER_SHIP_LIFE: u16 = 3000;
+pub const INVADER_BOARDER_LIFE: u16 = 500;
+/// Invader ship shield resistance in percent per damage type.
+pub const INVADER_SHIP_RESISTANCE_PERCENT: [u8; DAMAGE_TYPES] = [0, 50, 0];
+/// Boarder armor resistance in percent per damage type.
+pub const INVADER_BOARDER_RESISTANCE_PERCENT: [u8; DAMAGE_TYPES] = [50, 20, 0];
+/// Time between attacks of an invader.
+pub const INVADER_ATTACK_MILLISECONDS: u32 = 1000;
+/// Damage an invader ship deals to a spaceport per attack.
+pub const INVADER_SHIP_DAMAGE: u16 = 1000;
+/// People an invader ship kills per attack.
+pub const INVADER_SHIP_KILLS: u64 = 100;
+/// People a boarder kills per attack.
+pub const INVADER_BOARDER_KILLS: u64 = 50;
+
+/// People a canon kills in a city per 1000 damage.
+pub const CANON_CASUALTIES_PER_1000_DAMAGE: u64 = 100;
+
+/// A planet destroyer charging up to strike a planet.
+#[derive(Copy, Clone, Debug)]
+pub struct Charge {
+    pub shooter: usize,
+    pub hand: Hand,
+    pub weapon: usize,
+    pub planet: usize,
+    pub milliseconds_left: u32,
+}
+
+/// Something that happened, announced to all players.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Event {
+    /// A player started charging a planet destroyer at planet.
+    PlanetDestroyerCharging {shooter: usize, planet: usize},
+    /// A charging planet destroyer was interrupted.
+    PlanetDestroyerInterrupted {shooter: usize, planet: usize},
+    /// An explosion damaged target.
+    BlastDamage {target: Target, damage: u16},
+}
+
+/// A single weapon of some weapon type.
+pub struct WeaponItem {
+    /// The weapon type.
+    pub weapon: usize,
+    /// Rounds left in the magazine.
+    pub ammo: u16,
+    /// The player the weapon was handed out to or picked up by.
+    pub owner: Option<usize>,
+    /// Where the weapon lies when dropped.
+    pub lying: Option<LyingItem>,
+}
+
+/// An item lying on a planet, waiting to be picked up.
+#[derive(Copy, Clone, Debug)]
+pub struct LyingItem {
+    pub planet: usize,
+    /// Position in world coordinates.
+    pub pos: [f64; 3],
+    /// How long until the item despawns.
+    pub despawn_milliseconds: u32,
+}
+
+/// The number of holster slots players start with.
+pub const DEFAULT_HOLSTER_SLOTS: u8 = 2;
+/// How long it takes to move a weapon between hand and holster.
+pub const HOLSTER_SWAP_MILLISECONDS: u16 = 500;
+
+/// How many planet destroyers a loadout can contain.
+pub const MAX_PLANET_DESTROYERS_PER_LOADOUT: usize = 1;
+
+/// A named set of weapon types handed out when spawning.
+pub struct Loadout {
+    pub left_weapon: Option<usize>,
+    pub right_weapon: Option<usize>,
+    /// Weapon types by holster slot.
+    pub holsters: Vec<Option<usize>>,
+    /// The species allowed to select the loadout, if restricted.
+    pub species: Option<usize>,
+}
+
+impl Loadout {
+    /// Returns weapon types in hands and holsters.
+    pub fn weapons(&self) -> Vec<usize> {
+        let mut weapons: Vec<usize> = self.left_weapon.iter()
+            .chain(self.right_weapon.iter())
+            .cloned()
+            .collect();
+        weapons.extend(self.holsters.iter().filter_map(|&weapon| weapon));
+        weapons
+    }
+
+    pub fn weapon_mut(&mut self, hand: Hand) -> &mut Option<usize> {
+        match hand {
+            Hand::Left => &mut self.left_weapon,
+            Hand::Right => &mut self.right_weapon,
+        }
+    }
+}
+
+/// How long dropped weapons lie around before they despawn.
+pub const WEAPON_DESPAWN_MILLISECONDS: u32 = 30_000;
+/// How far in meters from the hand players can pick up weapons.
+pub const PICK_UP_RADIUS: f64 = 2.0;
+
+/// A projectile fired from a hand weapon.
+pub struct Projectile {
+    /// Position in meters.
+    pub pos: [f64; 3],
+    /// Velocity in meters per second.
+    pub vel: [f64; 3],
+    pub weapon: usize,
+    pub shooter: usize,
+    pub hand: Hand,
+    pub lifetime_milliseconds: u32,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -66,20 +348,277 @@ pub enum Hand {
     Right,
 }
 
+/// A tracked VR device.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Tracker {
+    Head,
+    Hand(Hand),
+}
+
+impl Tracker {
+    pub fn all() -> &'static [Tracker] {
+        &[
+            Tracker::Head,
+            Tracker::Hand(Hand::Left),
+            Tracker::Hand(Hand::Right),
+        ]
+    }
+}
+
+/// The scale of quaternion components in tracking commands.
+pub const ORIENTATION_SCALE: f32 = 10_000.0;
+
+/// Position and orientation of a tracked device.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Pose {
+    /// Position in meters.
+    pub pos: [f32; 3],
+    pub orientation: Quaternion<f32>,
+}
+
+impl Pose {
+    pub fn new(pos: [f32; 3]) -> Pose {
+        Pose {
+            pos,
+            orientation: quaternion::id(),
+        }
+    }
+
+    /// Creates pose from millimeters and scaled quaternion `[w, x, y, z]`.
+    pub fn from_tracking(pos: [i32; 3], orientation: [i16; 4]) -> Pose {
+        let f = |x: i16| x as f32 / ORIENTATION_SCALE;
+        Pose {
+            pos: [
+                pos[0] as f32 / 1000.0,
+                pos[1] as f32 / 1000.0,
+                pos[2] as f32 / 1000.0,
+            ],
+            orientation: (
+                f(orientation[0]),
+                [f(orientation[1]), f(orientation[2]), f(orientation[3])]
+            ),
+        }
+    }
+
+    /// Converts to millimeters and scaled quaternion `[w, x, y, z]`.
+    pub fn to_tracking(self) -> ([i32; 3], [i16; 4]) {
+        let f = |x: f32| (x * ORIENTATION_SCALE).round() as i16;
+        let (w, [x, y, z]) = self.orientation;
+        (
+            [
+                (self.pos[0] * 1000.0).round() as i32,
+                (self.pos[1] * 1000.0).round() as i32,
+                (self.pos[2] * 1000.0).round() as i32,
+            ],
+            [f(w), f(x), f(y), f(z)]
+        )
+    }
+}
+
+/// Something that can be hit.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Target {
+    Player(usize),
+    Spaceship(usize),
+    Planet(usize),
+    Spaceport(usize),
+}
+
+/// A part of a player's body that can be hit.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum HitZone {
+    Head,
+    Torso,
+    Hand(Hand),
+}
+
+/// The result of casting a ray.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RayHit {
+    pub target: Target,
+    /// Which part of a player was hit.
+    pub zone: Option<HitZone>,
+    /// Distance along the ray in meters.
+    pub distance: f64,
+    /// Impact point in world coordinates.
+    pub pos: [f64; 3],
+}
+
+/// A phase in the lifecycle of a match.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Phase {
+    /// Players join, pick teams, loadouts and game mode.
+    Lobby,
+    /// Players can practice before the match.
+    Warmup,
+    /// Players wait for the match to start.
+    Countdown,
+    InProgress,
+    /// Extra time when no winner is decided within the time limit.
+    Overtime,
+    Ended,
+}
+
+impl Phase {
+    /// Returns the phase coming after a timed phase.
+    pub fn next(self, overtime: bool) -> Phase {
+        match self {
+            Phase::Lobby => Phase::Warmup,
+            Phase::Warmup => Phase::Countdown,
+            Phase::Countdown => Phase::InProgress,
+            Phase::InProgress if overtime => Phase::Overtime,
+            Phase::InProgress | Phase::Overtime | Phase::Ended => Phase::Ended,
+        }
+    }
+}
+
+pub const DEFAULT_WARMUP_MILLISECONDS: u32 = 30_000;
+pub const DEFAULT_COUNTDOWN_MILLISECONDS: u32 = 10_000;
+pub const DEFAULT_MATCH_MILLISECONDS: u32 = 600_000;
+pub const DEFAULT_OVERTIME_MILLISECONDS: u32 = 60_000;
+
+/// How damage between teammates is treated.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum FriendlyFire {
+    /// Teammates can not damage each other.
+    Off,
+    /// Teammates deal reduced damage to each other.
+    Reduced,
+    /// Teammates deal full damage to each other.
+    Full,
+}
+
+/// The damage in percent teammates deal with reduced friendly fire.
+pub const REDUCED_FRIENDLY_FIRE_PERCENT: u32 = 50;
+
+/// A lingering effect on a player.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum StatusEffect {
+    /// Takes damage over time.
+    Burning = 0,
+    /// Cannot use weapons.
+    Stunned = 1,
+    /// Weapons recharge at half speed.
+    Slowed = 2,
+    /// Weapons cannot recharge.
+    Emp = 3,
+}
+
+impl StatusEffect {
+    pub fn all() -> &'static [StatusEffect] {
+        &[
+            StatusEffect::Burning,
+            StatusEffect::Stunned,
+            StatusEffect::Slowed,
+            StatusEffect::Emp,
+        ]
+    }
+}
+
+/// How much damage burning players take per second.
+pub const BURNING_DAMAGE_PER_SECOND: u16 = 50;
+
 pub const DEFAULT_PLAYER_LIFE: u16 = 1000;
+/// The radius in meters of the capsule used for hitting player torso.
+pub const PLAYER_RADIUS: f64 = 0.3;
+/// The radius in meters of the sphere used for hitting player head.
+pub const HEAD_RADIUS: f64 = 0.15;
+/// The radius in meters of the sphere used for hitting player hands.
+pub const HAND_RADIUS: f64 = 0.08;
+pub const DEFAULT_HEAD_DAMAGE_PERCENT: u16 = 200;
+pub const DEFAULT_TORSO_DAMAGE_PERCENT: u16 = 100;
+pub const DEFAULT_HAND_DAMAGE_PERCENT: u16 = 50;
+/// Shield resistance in percent per damage type.
+pub const DEFAULT_SHIELD_RESISTANCE_PERCENT: [u8; DAMAGE_TYPES] = [0, 50, 0];
+/// Armor resistance in percent per damage type.
+pub const DEFAULT_ARMOR_RESISTANCE_PERCENT: [u8; DAMAGE_TYPES] = [50, 20, 0];
+/// How much shield regenerates per second.
+pub const SHIELD_REGENERATION_PER_SECOND: u16 = 50;
+/// How long time after taking damage before shield starts regenerating.
+pub const SHIELD_REGENERATION_DELAY_MILLISECONDS: u32 = 3000;
+
+/// How much life regenerates per second.
+pub const LIFE_REGENERATION_PER_SECOND: u16 = 20;
+/// How long time after taking damage before life starts regenerating.
+pub const LIFE_REGENERATION_DELAY_MILLISECONDS: u32 = 5000;
+/// Head height in meters above the frame origin when spawning.
+pub const DEFAULT_HEAD_HEIGHT: f32 = 1.7;
+/// Hand height in meters above the frame origin when spawning.
+pub const DEFAULT_HAND_HEIGHT: f32 = 1.0;
 
 pub struct Player {
+    /// Weapon item in left hand.
     pub left_weapon: Option<usize>,
+    /// Weapon item in right hand.
     pub right_weapon: Option<usize>,
+    /// Weapon items in holster slots, e.g. hip and back.
+    pub holsters: Vec<Option<usize>>,
+    /// The loadout applied when spawning.
+    pub loadout: Option<usize>,
     pub left_recharge_milliseconds: u16,
     pub right_recharge_milliseconds: u16,
+    /// Rounds carried for reloading.
+    pub spare_ammo: u16,
     pub species: Option<usize>,
+    pub team: Option<usize>,
     pub on_planet: Option<usize>,
+    /// The location on the planet where the player spawned.
+    pub location: u8,
+    pub on_spaceship: Option<usize>,
+    /// Head pose relative to the planet location or spaceship.
+    pub head: Pose,
+    /// Left hand pose relative to the planet location or spaceship.
+    pub left_hand: Pose,
+    /// Right hand pose relative to the planet location or spaceship.
+    pub right_hand: Pose,
+    /// What the last shot fired with left hand hit.
+    pub left_last_hit: Option<Target>,
+    /// What the last shot fired with right hand hit.
+    pub right_last_hit: Option<Target>,
+    /// Where the player was last hit.
+    pub last_hit_zone: Option<HitZone>,
     pub life: u16,
+    /// Shield absorbs damage before armor and life.
+    pub shield: u16,
+    /// The shield capacity it regenerates up to.
+    pub max_shield: u16,
+    /// How long time since player took damage.
+    pub milliseconds_since_damage: u32,
+    /// Armor absorbs a part of the damage passing through shield.
+    pub armor: u16,
+    pub shield_resistance_percent: [u8; DAMAGE_TYPES],
+    pub armor_resistance_percent: [u8; DAMAGE_TYPES],
+    /// Remaining time of each status effect.
+    pub status_milliseconds: [u32; STATUS_EFFECTS],
+    /// The attack that last applied each status effect.
+    pub status_attacks: [Option<Attack>; STATUS_EFFECTS],
+    /// Burning damage in thousandths not yet applied.
+    pub burning_remainder: u32,
     pub dead: bool,
+    /// How player last died.
+    pub death: Option<Death>,
+    /// Attackers that damaged player since its last death, and when.
+    pub damage_taken: Vec<(usize, u64)>,
+    pub stats: Stats,
 }
 
 impl Player {
+    pub fn pose(&self, tracker: Tracker) -> Pose {
+        match tracker {
+            Tracker::Head => self.head,
+            Tracker::Hand(Hand::Left) => self.left_hand,
+            Tracker::Hand(Hand::Right) => self.right_hand,
+        }
+    }
+
+    pub fn pose_mut(&mut self, tracker: Tracker) -> &mut Pose {
+        match tracker {
+            Tracker::Head => &mut self.head,
+            Tracker::Hand(Hand::Left) => &mut self.left_hand,
+            Tracker::Hand(Hand::Right) => &mut self.right_hand,
+        }
+    }
+
     pub fn weapon_mut(&mut self, hand: Hand) -> &mut Option<usize> {
         match hand {
             Hand::Left => &mut self.left_weapon,
@@ -94,9 +633,59 @@ impl Player {
         }
     }
 
+    pub fn last_hit_mut(&mut self, hand: Hand) -> &mut Option<Target> {
+        match hand {
+            Hand::Left => &mut self.left_last_hit,
+            Hand::Right => &mut self.right_last_hit,
+        }
+    }
+
+    pub fn has_status(&self, effect: StatusEffect) -> bool {
+        self.status_milliseconds[effect as usize] > 0
+    }
+
+    /// Returns how much weapons recharge in an amount of milliseconds.
+    pub fn recharge_rate(&self, milliseconds: u16) -> u16 {
+        if self.has_status(StatusEffect::Emp) {
+            0
+        } else if self.has_status(StatusEffect::Slowed) {
+            milliseconds / 2
+        } else {
+            milliseconds
+        }
+    }
+
+    /// Returns `true` if player can shoot with weapon in hand.
+    pub fn can_shoot(&self, hand: Hand) -> bool {
+        let recharge_milliseconds = match hand {
+            Hand::Left => self.left_recharge_milliseconds,
+            Hand::Right => self.right_recharge_milliseconds,
+        };
+        recharge_milliseconds == 0 &&
+        !self.has_status(StatusEffect::Stunned)
+    }
+
     pub fn has_weapons(&self) -> bool {
-        self.left_weapon.is_some() ||
-        self.right_weapon.is_some()
+        !self.carried_weapons().is_empty()
+    }
+
+    /// Returns weapon items in hands and holsters.
+    pub fn carried_weapons(&self) -> Vec<usize> {
+        let mut items: Vec<usize> = self.left_weapon.iter()
+            .chain(self.right_weapon.iter())
+            .cloned()
+            .collect();
+        items.extend(self.holsters.iter().filter_map(|&item| item));
+        items
+    }
+
+    /// Returns the side player fights on, its team or else its species.
+    pub fn side(&self) -> Option<Side> {
+        if let Some(team_id) = self.team {
+            Some(Side::Team(team_id))
+        } else {
+            self.species.map(Side::Species)
+        }
     }
 
     /// Returns the planet to spawn from at start of game.
@@ -170,6 +759,11 @@ impl CanonSlot {
 }
 
 pub struct Spaceship {
+    /// Position in meters.
+    pub pos: [f64; 3],
+    /// The spaceship is destroyed when its hull reaches zero.
+    pub hull: u16,
+    pub destroyed: bool,
     canon_front_1: Option<usize>,
     canon_front_2: Option<usize>,
     canon_left_side_1: Option<usize>,
@@ -211,10 +805,19 @@ impl Spaceship {
     }
 }
 
+/// How high in meters above a planet's surface a spaceship is in orbit of it.
+pub const ORBIT_ALTITUDE: f64 = 1_000_000.0;
+/// The radius in meters of the sphere used for hitting spaceships.
+pub const SPACESHIP_RADIUS: f64 = 50.0;
+
+pub const DEFAULT_SPACESHIP_HULL: u16 = 5000;
+
 pub const DEFAULT_CANON_FIREPOWER: u16 = 1000;
 
 pub struct Canon {
     pub firepower: u16,
+    /// Explosions damage everything within this radius in meters.
+    pub blast_radius: f64,
 }
 
 pub struct World {
@@ -224,9 +827,55 @@ pub struct World {
     pub cities: Vec<City>,
     pub spaceports: Vec<Spaceport>,
     pub weapons: Vec<Weapon>,
+    pub weapon_items: Vec<WeaponItem>,
+    pub loadouts: Vec<Loadout>,
+    pub teams: Vec<Team>,
+    /// The simulation clock, time since the world was created.
+    pub milliseconds: u64,
+    /// People migrating per mille per second from cities with working spaceports.
+    pub migration_per_mille: u32,
+    /// Time since the last population tick.
+    pub population_milliseconds: u32,
+    /// Waves of invaders attacking the planet defended in the game mode.
+    pub waves: Vec<Wave>,
+    /// The number of waves that have arrived.
+    pub waves_arrived: usize,
+    /// Time since the last wave arrived.
+    pub wave_milliseconds: u32,
+    pub invaders: Vec<Invader>,
+    pub game_mode: Box<dyn GameMode>,
+    /// Time a team must hold a spaceport alone to capture it.
+    pub capture_milliseconds: u32,
+    /// The phase of the match, or `None` for free play without a match.
+    pub phase: Option<Phase>,
+    /// Time left of the current phase.
+    pub phase_milliseconds: u32,
+    pub warmup_milliseconds: u32,
+    pub countdown_milliseconds: u32,
+    /// The time limit of the match.
+    pub match_milliseconds: u32,
+    pub overtime_milliseconds: u32,
+    pub charges: Vec<Charge>,
+    /// Log of events announced to all players.
+    pub events: Vec<Event>,
+    /// The maximum number of planet destroyers in play, if limited.
+    pub planet_destroyer_limit: Option<u16>,
+    /// Whether planet destroyers can only be fired aboard a spaceship.
+    pub planet_destroyer_requires_spaceship: bool,
+    pub friendly_fire: FriendlyFire,
+    /// Whether players can damage themselves.
+    pub self_damage: bool,
     pub players: Vec<Player>,
     pub spaceships: Vec<Spaceship>,
     pub canons: Vec<Canon>,
+    pub projectiles: Vec<Projectile>,
+    pub rng: Rng,
+    pub head_damage_percent: u16,
+    pub torso_damage_percent: u16,
+    /// Damage multiplier shared by both hands.
+    pub hand_damage_percent: u16,
+    /// The maximum life that players can heal up to.
+    pub max_life: u16,
 }
 
 impl World {
@@ -238,9 +887,39 @@ impl World {
             cities: vec![],
             spaceports: vec![],
             weapons: vec![],
+            weapon_items: vec![],
+            loadouts: vec![],
+            teams: vec![],
+            milliseconds: 0,
+            migration_per_mille: 0,
+            population_milliseconds: 0,
+            waves: vec![],
+            waves_arrived: 0,
+            wave_milliseconds: 0,
+            invaders: vec![],
+            game_mode: Box::new(DeathMatch),
+            capture_milliseconds: DEFAULT_CAPTURE_MILLISECONDS,
+            phase: None,
+            phase_milliseconds: 0,
+            warmup_milliseconds: DEFAULT_WARMUP_MILLISECONDS,
+            countdown_milliseconds: DEFAULT_COUNTDOWN_MILLISECONDS,
+            match_milliseconds: DEFAULT_MATCH_MILLISECONDS,
+            overtime_milliseconds: DEFAULT_OVERTIME_MILLISECONDS,
+            charges: vec![],
+            events: vec![],
+            planet_destroyer_limit: None,
+            planet_destroyer_requires_spaceship: false,
+            friendly_fire: FriendlyFire::Full,
+            self_damage: true,
             players: vec![],
             spaceships: vec![],
             canons: vec![],
+            projectiles: vec![],
+            rng: Rng::new(DEFAULT_SEED),
+            head_damage_percent: DEFAULT_HEAD_DAMAGE_PERCENT,
+            torso_damage_percent: DEFAULT_TORSO_DAMAGE_PERCENT,
+            hand_damage_percent: DEFAULT_HAND_DAMAGE_PERCENT,
+            max_life: DEFAULT_PLAYER_LIFE,
         }
     }
 
@@ -249,10 +928,12 @@ impl World {
         let id = self.planets.len();
         self.planets.push(Planet {
             pos: [0.0; 3],
+            radius: DEFAULT_PLANET_RADIUS,
             orbit: None,
             cities: [None; LOCATIONS_PER_PLANET],
             spaceports: [None; LOCATIONS_PER_PLANET],
             destroyed: false,
+            casualties: [0; SPECIES],
         });
         id
     }
@@ -264,11 +945,61 @@ impl World {
         id
     }
 
+    /// Creates a new team.
+    pub fn create_team(&mut self) -> usize {
+        let id = self.teams.len();
+        self.teams.push(Team {
+            allies: vec![],
+            points: 0,
+            point_milliseconds: 0,
+        });
+        id
+    }
+
+    /// Makes two different teams allies.
+    pub fn ally_teams(&mut self, a: usize, b: usize) -> Result<(), ()> {
+        if a == b {return Err(())};
+        if !self.teams[a].allies.contains(&b) {
+            self.teams[a].allies.push(b);
+            self.teams[b].allies.push(a);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if two sides fight together.
+    pub fn are_allied_sides(&self, a: Side, b: Side) -> bool {
+        match (a, b) {
+            (Side::Team(a), Side::Team(b)) => a == b || self.teams[a].allies.contains(&b),
+            _ => a == b,
+        }
+    }
+
+    /// Returns the teams of remaining players when they all are on allied teams.
+    pub fn winning_teams(&self) -> Vec<usize> {
+        let mut teams: Vec<usize> = vec![];
+        for player in &self.players {
+            if player.out_of_game(self) {continue};
+            match player.team {
+                None => return vec![],
+                Some(team_id) => {
+                    if teams.iter().any(|&id| !self.are_allied_sides(Side::Team(id), Side::Team(team_id))) {
+                        return vec![];
+                    }
+                    if !teams.contains(&team_id) {
+                        teams.push(team_id);
+                    }
+                }
+            }
+        }
+        teams
+    }
+
     /// Creates a new species.
     pub fn create_species(&mut self) -> usize {
         let id = self.species.len();
         self.species.push(Species {
             home_planet: None,
+            growth_per_mille: 0,
         });
         id
     }
@@ -280,6 +1011,8 @@ impl World {
             planet: None,
             location: None,
             population: [0; SPECIES],
+            growth_remainder: [0; SPECIES],
+            medkits: 0,
         });
         id
     }
@@ -289,6 +1022,11 @@ impl World {
         let id = self.spaceports.len();
         self.spaceports.push(Spaceport {
             destroyed: false,
+            hull: DEFAULT_SPACEPORT_HULL,
+            ammo_packs: 0,
+            owner: None,
+            capturing: None,
+            capture_milliseconds: 0,
         });
         id
     }
@@ -305,29 +1043,165 @@ impl World {
         }
     }
 
+    pub fn hit_zone_damage_percent_mut(&mut self, zone: HitZone) -> &mut u16 {
+        match zone {
+            HitZone::Head => &mut self.head_damage_percent,
+            HitZone::Torso => &mut self.torso_damage_percent,
+            HitZone::Hand(_) => &mut self.hand_damage_percent,
+        }
+    }
+
     /// Creates a new weapon.
     pub fn create_weapon(&mut self) -> usize {
         let id = self.weapons.len();
         self.weapons.push(Weapon {
             firepower: DEFAULT_WEAPON_FIREPOWER,
+            damage_type: DamageType::Kinetic,
             recharge_milliseconds: DEFAULT_WEAPON_RECHARGE_MILLISECONDS,
             planet_destroyer: false,
+            healing: 0,
+            projectile_speed: None,
+            projectile_lifetime_milliseconds: DEFAULT_PROJECTILE_LIFETIME_MILLISECONDS,
+            spread_milliradians: 0,
+            accuracy_percent: 100,
+            damage_variance: 0,
+            status_effect: None,
+            magazine: None,
+            reload_milliseconds: DEFAULT_WEAPON_RELOAD_MILLISECONDS,
+            charge_milliseconds: 0,
+            blast_radius: 0.0,
+        });
+        id
+    }
+
+    /// Creates a new weapon item of a weapon type with a full magazine.
+    pub fn create_weapon_item(&mut self, weapon_id: usize) -> usize {
+        let id = self.weapon_items.len();
+        self.weapon_items.push(WeaponItem {
+            weapon: weapon_id,
+            ammo: self.weapons[weapon_id].magazine.unwrap_or(0),
+            owner: None,
+            lying: None,
+        });
+        id
+    }
+
+    /// Creates a new empty loadout.
+    pub fn create_loadout(&mut self) -> usize {
+        let id = self.loadouts.len();
+        self.loadouts.push(Loadout {
+            left_weapon: None,
+            right_weapon: None,
+            holsters: vec![],
+            species: None,
         });
         id
     }
 
+    /// Puts a weapon type in loadout holster slot.
+    pub fn set_loadout_holster(&mut self, loadout_id: usize, weapon_id: usize, slot: u8) {
+        let holsters = &mut self.loadouts[loadout_id].holsters;
+        if holsters.len() <= slot as usize {
+            holsters.resize(slot as usize + 1, None);
+        }
+        holsters[slot as usize] = Some(weapon_id);
+    }
+
+    /// Returns `true` if loadout respects weapon restrictions.
+    pub fn loadout_valid(&self, loadout_id: usize) -> bool {
+        self.loadouts[loadout_id].weapons().iter()
+            .filter(|&&weapon_id| self.weapons[weapon_id].planet_destroyer)
+            .count() <= MAX_PLANET_DESTROYERS_PER_LOADOUT
+    }
+
+    /// Player selects loadout to be applied when spawning.
+    /// The loadout must be valid, allowed for the player's species
+    /// and fit into the player's holster slots.
+    pub fn select_loadout(&mut self, player_id: usize, loadout_id: usize) -> Result<(), ()> {
+        if !self.loadout_allowed(player_id, loadout_id) {return Err(())};
+        self.players[player_id].loadout = Some(loadout_id);
+        Ok(())
+    }
+
+    /// Returns `true` if loadout is valid and fits the player.
+    fn loadout_allowed(&self, player_id: usize, loadout_id: usize) -> bool {
+        let loadout = &self.loadouts[loadout_id];
+        let player = &self.players[player_id];
+        self.loadout_valid(loadout_id) &&
+        (loadout.species.is_none() || loadout.species == player.species) &&
+        loadout.holsters.len() <= player.holsters.len()
+    }
+
+    /// Replaces the weapons of player with new items from the selected loadout.
+    /// The weapons previously carried are despawned.
+    /// Fails if the loadout was changed to no longer be allowed since it was selected,
+    /// or if a weapon of the loadout can not be handed out.
+    pub fn apply_loadout(&mut self, player_id: usize) -> Result<(), ()> {
+        let loadout_id = match self.players[player_id].loadout {
+            None => return Ok(()),
+            Some(x) => x,
+        };
+        if !self.loadout_allowed(player_id, loadout_id) {return Err(())};
+        for item_id in self.players[player_id].carried_weapons() {
+            self.despawn_weapon_item(item_id);
+        }
+        let player = &mut self.players[player_id];
+        player.left_weapon = None;
+        player.right_weapon = None;
+        for slot in &mut player.holsters {
+            *slot = None;
+        }
+
+        for &hand in &[Hand::Left, Hand::Right] {
+            if let Some(weapon_id) = *self.loadouts[loadout_id].weapon_mut(hand) {
+                self.assign_weapon(player_id, weapon_id, hand)?;
+            }
+        }
+        let holsters = self.loadouts[loadout_id].holsters.clone();
+        for (slot, weapon) in holsters.into_iter().enumerate() {
+            if let Some(weapon_id) = weapon {
+                self.assign_holster(player_id, weapon_id, slot as u8)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Creates a new player.
     pub fn create_player(&mut self) -> usize {
         let id = self.players.len();
         self.players.push(Player {
             left_weapon: None,
             right_weapon: None,
+            holsters: vec![None; DEFAULT_HOLSTER_SLOTS as usize],
+            loadout: None,
             left_recharge_milliseconds: 0,
             right_recharge_milliseconds: 0,
+            spare_ammo: 0,
             species: None,
+            team: None,
             on_planet: None,
+            location: 0,
+            on_spaceship: None,
+            head: Pose::new([0.0, DEFAULT_HEAD_HEIGHT, 0.0]),
+            left_hand: Pose::new([0.0, DEFAULT_HAND_HEIGHT, 0.0]),
+            right_hand: Pose::new([0.0, DEFAULT_HAND_HEIGHT, 0.0]),
+            left_last_hit: None,
+            right_last_hit: None,
+            last_hit_zone: None,
             life: DEFAULT_PLAYER_LIFE,
+            shield: 0,
+            max_shield: 0,
+            milliseconds_since_damage: 0,
+            armor: 0,
+            shield_resistance_percent: DEFAULT_SHIELD_RESISTANCE_PERCENT,
+            armor_resistance_percent: DEFAULT_ARMOR_RESISTANCE_PERCENT,
+            status_milliseconds: [0; STATUS_EFFECTS],
+            status_attacks: [None; STATUS_EFFECTS],
+            burning_remainder: 0,
             dead: false,
+            death: None,
+            damage_taken: vec![],
+            stats: Stats::default(),
         });
         id
     }
@@ -336,6 +1210,9 @@ impl World {
     pub fn create_spaceship(&mut self) -> usize {
         let id = self.spaceships.len();
         self.spaceships.push(Spaceship {
+            pos: [0.0; 3],
+            hull: DEFAULT_SPACESHIP_HULL,
+            destroyed: false,
             canon_front_1: None,
             canon_front_2: None,
             canon_left_side_1: None,
@@ -361,6 +1238,7 @@ impl World {
         let id = self.canons.len();
         self.canons.push(Canon {
             firepower: DEFAULT_CANON_FIREPOWER,
+            blast_radius: 0.0,
         });
         id
     }
@@ -421,8 +1299,8 @@ impl World {
     pub fn number_of_weapon_users(&self, weapon_id: usize) -> usize {
         let mut sum = 0;
         for player in &self.players {
-            if player.left_weapon == Some(weapon_id) ||
-               player.right_weapon == Some(weapon_id)
+            if player.carried_weapons().iter()
+                .any(|&item| self.weapon_items[item].weapon == weapon_id)
             {
                 sum += 1;
             }
@@ -431,20 +1309,96 @@ impl World {
     }
 
     /// Player shoots at planet.
+    /// Fails if the weapon is a planet destroyer the player is not allowed to fire.
     pub fn shoot_at_planet(
         &mut self,
         player_id: usize,
         hand: Hand,
         planet_id: usize
-    ) {
-        if let Some(weapon_id) = *self.players[player_id].weapon_mut(hand) {
-            // Recharge weapon.
-            let recharge_milliseconds = self.weapons[weapon_id].recharge_milliseconds;
-            *self.players[player_id].recharge_milliseconds_mut(hand) = recharge_milliseconds;
+    ) -> Result<(), ()> {
+        if let Some(weapon_id) = self.hand_weapon(player_id, hand) {
+            if self.weapons[weapon_id].planet_destroyer &&
+               !self.can_fire_planet_destroyer(player_id, planet_id)
+            {
+                return Err(());
+            }
+        }
+        if let Some(weapon_id) = self.pull_trigger(player_id, hand) {
+            self.players[player_id].stats.shots_hit += 1;
+            self.strike_planet(player_id, hand, weapon_id, planet_id);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if player is allowed to fire planet destroyers at planet.
+    /// When required, the player must be aboard a spaceship in orbit of the planet.
+    pub fn can_fire_planet_destroyer(&self, player_id: usize, planet_id: usize) -> bool {
+        !self.planet_destroyer_requires_spaceship ||
+        self.players[player_id].on_spaceship
+            .map(|spaceship_id| self.in_orbit(spaceship_id, planet_id))
+            .unwrap_or(false)
+    }
+
+    /// Weapon fired by player strikes planet.
+    /// Planet destroyers that need charging start a charge announced to all players.
+    /// Planet destroyers the shooter is not allowed to fire have no effect.
+    pub fn strike_planet(&mut self, shooter_id: usize, hand: Hand, weapon_id: usize, planet_id: usize) {
+        let planet_destroyer = self.weapons[weapon_id].planet_destroyer;
+        if planet_destroyer && !self.can_fire_planet_destroyer(shooter_id, planet_id) {return};
+        let charge_milliseconds = self.weapons[weapon_id].charge_milliseconds;
+        if planet_destroyer && charge_milliseconds > 0 {
+            if self.charges.iter().any(|charge| charge.shooter == shooter_id) {return};
+            self.charges.push(Charge {
+                shooter: shooter_id,
+                hand,
+                weapon: weapon_id,
+                planet: planet_id,
+                milliseconds_left: charge_milliseconds,
+            });
+            self.events.push(Event::PlanetDestroyerCharging {
+                shooter: shooter_id,
+                planet: planet_id,
+            });
+        } else {
+            self.hit_planet(shooter_id, weapon_id, planet_id);
+        }
+    }
+
+    /// Interrupts the charge of player's planet destroyer.
+    pub fn interrupt_charge(&mut self, player_id: usize) {
+        let mut i = 0;
+        while i < self.charges.len() {
+            if self.charges[i].shooter == player_id {
+                let charge = self.charges.remove(i);
+                self.events.push(Event::PlanetDestroyerInterrupted {
+                    shooter: charge.shooter,
+                    planet: charge.planet,
+                });
+            } else {
+                i += 1;
+            }
+        }
+    }
 
-            // Hit planet.
-            if self.weapons[weapon_id].planet_destroyer {
-                self.planets[planet_id].destroyed = true;
+    /// Counts down charging planet destroyers and strikes when fully charged.
+    /// A charge is interrupted when the shooter dies or stops holding the weapon.
+    pub fn update_charges(&mut self, milliseconds: u16) {
+        let mut i = 0;
+        while i < self.charges.len() {
+            let charge = self.charges[i];
+            if self.players[charge.shooter].dead ||
+               self.hand_weapon(charge.shooter, charge.hand) != Some(charge.weapon) ||
+               !self.can_fire_planet_destroyer(charge.shooter, charge.planet)
+            {
+                self.interrupt_charge(charge.shooter);
+                continue;
+            }
+            if milliseconds as u32 >= charge.milliseconds_left {
+                self.charges.remove(i);
+                self.hit_planet(charge.shooter, charge.weapon, charge.planet);
+            } else {
+                self.charges[i].milliseconds_left -= milliseconds as u32;
+                i += 1;
             }
         }
     }
@@ -456,76 +1410,1450 @@ impl World {
         hand: Hand,
         target_id: usize
     ) {
-        if let Some(weapon_id) = *self.players[shooter_id].weapon_mut(hand) {
-            // Recharge weapon.
-            let recharge_milliseconds = self.weapons[weapon_id].recharge_milliseconds;
-            *self.players[shooter_id].recharge_milliseconds_mut(hand) = recharge_milliseconds;
-
-            // Hit target player.
-            let firepower = self.weapons[weapon_id].firepower;
-            let life = self.players[target_id].life;
-            if firepower >= life {
-                self.players[target_id].life = 0;
-                self.players[target_id].dead = true;
-            } else {
-                self.players[target_id].life -= firepower;
+        if let Some(weapon_id) = self.pull_trigger(shooter_id, hand) {
+            // Roll for hit.
+            let accuracy_percent = self.weapons[weapon_id].accuracy_percent;
+            if accuracy_percent < 100 &&
+               self.rng.next_u64() % 100 >= accuracy_percent as u64
+            {
+                return;
             }
+
+            self.players[shooter_id].stats.shots_hit += 1;
+            self.hit_player(shooter_id, hand, weapon_id, target_id, HitZone::Torso);
         }
     }
 
-    /// Player shoots at nothing.
-    pub fn shoot_at_nothing(
-        &mut self,
-        player_id: usize,
-        hand: Hand
-    ) {
-        if let Some(weapon_id) = *self.players[player_id].weapon_mut(hand) {
-            let recharge_milliseconds = self.weapons[weapon_id].recharge_milliseconds;
-            *self.players[player_id].recharge_milliseconds_mut(hand) = recharge_milliseconds;
+    /// Returns the damage in percent that attacker deals to target,
+    /// following the friendly fire and self damage rules.
+    pub fn damage_percent(&self, attacker_id: usize, target_id: usize) -> u32 {
+        if attacker_id == target_id {
+            if self.self_damage {100} else {0}
+        } else if self.are_teammates(attacker_id, target_id) {
+            match self.friendly_fire {
+                FriendlyFire::Off => 0,
+                FriendlyFire::Reduced => REDUCED_FRIENDLY_FIRE_PERCENT,
+                FriendlyFire::Full => 100,
+            }
+        } else {
+            100
         }
     }
 
-    /// Recharges weapon with an amount of milliseconds.
-    pub fn recharge_milliseconds(
+    /// Player hits another player in a zone with weapon held in hand.
+    /// A hit on the hand makes the player drop the weapon in that hand.
+    /// Hits that deal no damage by the friendly fire rules have no effect.
+    pub fn hit_player(
         &mut self,
-        player_id: usize,
+        shooter_id: usize,
         hand: Hand,
-        recharge_milliseconds: u16
+        weapon_id: usize,
+        target_id: usize,
+        zone: HitZone
     ) {
-        let ms = self.players[player_id].recharge_milliseconds_mut(hand);
-        if recharge_milliseconds > *ms {
-            *ms = 0;
-        } else {
-            *ms -= recharge_milliseconds;
+        let friendly_percent = self.damage_percent(shooter_id, target_id);
+        if friendly_percent == 0 {return};
+        let percent = *self.hit_zone_damage_percent_mut(zone) as u32;
+        let damage = self.roll_damage(weapon_id) as u32 * percent / 100 * friendly_percent / 100;
+        let damage_type = self.weapons[weapon_id].damage_type;
+        self.players[target_id].last_hit_zone = Some(zone);
+        let damage = damage.min(u16::MAX as u32) as u16;
+        let attack = Attack {attacker: shooter_id, cause: Cause::Weapon(weapon_id), hand: Some(hand)};
+        self.damage_player_by(Some(attack), target_id, damage, damage_type);
+        if let HitZone::Hand(hand) = zone {
+            self.drop_weapon(target_id, hand);
+        }
+        if let Some((effect, milliseconds)) = self.weapons[weapon_id].status_effect {
+            self.apply_status(Some(attack), target_id, effect, milliseconds);
         }
     }
 
-    /// Recharges all weapons with an amount of milliseconds.
-    pub fn recharge_milliseconds_all_weapons(
+    /// Applies status effect to player by an attack.
+    /// A shorter duration does not cut an effect already applied.
+    /// Damage from the effect is credited to the last attack applying it.
+    pub fn apply_status(
         &mut self,
-        recharge_milliseconds: u16
+        attack: Option<Attack>,
+        player_id: usize,
+        effect: StatusEffect,
+        milliseconds: u32
     ) {
-        for player in &mut self.players {
-            if recharge_milliseconds > player.left_recharge_milliseconds {
-                player.left_recharge_milliseconds = 0;
-            } else {
-                player.left_recharge_milliseconds -= recharge_milliseconds;
+        let player = &mut self.players[player_id];
+        if player.dead {return};
+        let ms = &mut player.status_milliseconds[effect as usize];
+        *ms = (*ms).max(milliseconds);
+        player.status_attacks[effect as usize] = attack;
+    }
+
+    /// Ticks down status effects and applies burning damage.
+    /// Damage less than a point carries over to the next update.
+    pub fn update_status_effects(&mut self, milliseconds: u16) {
+        for i in 0..self.players.len() {
+            if self.players[i].dead {continue};
+            let burning_ms = self.players[i].status_milliseconds[StatusEffect::Burning as usize]
+                .min(milliseconds as u32);
+            if burning_ms > 0 {
+                let player = &mut self.players[i];
+                let total = BURNING_DAMAGE_PER_SECOND as u32 * burning_ms + player.burning_remainder;
+                player.burning_remainder = total % 1000;
+                let damage = total / 1000;
+                if damage > 0 {
+                    let attack = player.status_attacks[StatusEffect::Burning as usize];
+                    self.damage_player_by(attack, i, damage as u16, DamageType::Energy);
+                }
             }
-            if recharge_milliseconds > player.right_recharge_milliseconds {
-                player.right_recharge_milliseconds = 0;
-            } else {
-                player.right_recharge_milliseconds -= recharge_milliseconds;
+            for ms in &mut self.players[i].status_milliseconds {
+                *ms = ms.saturating_sub(milliseconds as u32);
             }
         }
     }
 
-    /// Spawns player.
-    pub fn spawn(
-        &mut self,
-        player_id: usize
-    ) {
-        if let Some(planet_id) = self.players[player_id].spawning_planet(self) {
-            self.players[player_id].on_planet = Some(planet_id);
-        }
+    /// Returns the number of planet destroyers carried or lying around.
+    pub fn number_of_planet_destroyers(&self) -> usize {
+        let carried = self.players.iter()
+            .flat_map(|player| player.carried_weapons())
+            .filter(|&item_id| self.weapons[self.weapon_items[item_id].weapon].planet_destroyer)
+            .count();
+        let lying = self.weapon_items.iter()
+            .filter(|item| item.lying.is_some() && self.weapons[item.weapon].planet_destroyer)
+            .count();
+        carried + lying
+    }
+
+    /// Returns `true` if a new item of weapon type can be handed out
+    /// without exceeding the planet destroyer limit.
+    pub fn can_hand_out(&self, weapon_id: usize) -> bool {
+        if !self.weapons[weapon_id].planet_destroyer {return true};
+        match self.planet_destroyer_limit {
+            None => true,
+            Some(limit) => self.number_of_planet_destroyers() < limit as usize,
+        }
+    }
+
+    /// Puts a new weapon item of a weapon type in player's hand.
+    pub fn assign_weapon(&mut self, player_id: usize, weapon_id: usize, hand: Hand) -> Result<(), ()> {
+        if !self.can_hand_out(weapon_id) {return Err(())};
+        let item_id = self.create_weapon_item(weapon_id);
+        self.weapon_items[item_id].owner = Some(player_id);
+        *self.players[player_id].weapon_mut(hand) = Some(item_id);
+        Ok(())
+    }
+
+    /// Puts a new weapon item of a weapon type in player's holster slot.
+    pub fn assign_holster(&mut self, player_id: usize, weapon_id: usize, slot: u8) -> Result<(), ()> {
+        if slot as usize >= self.players[player_id].holsters.len() ||
+           !self.can_hand_out(weapon_id)
+        {
+            return Err(());
+        }
+        let item_id = self.create_weapon_item(weapon_id);
+        self.weapon_items[item_id].owner = Some(player_id);
+        self.players[player_id].holsters[slot as usize] = Some(item_id);
+        Ok(())
+    }
+
+    /// Sets the number of holster slots of player.
+    /// Weapons in removed slots are dropped.
+    pub fn set_holster_slots(&mut self, player_id: usize, slots: u8) {
+        let removed: Vec<usize> = self.players[player_id].holsters.iter()
+            .skip(slots as usize)
+            .filter_map(|&item| item)
+            .collect();
+        self.players[player_id].holsters.resize(slots as usize, None);
+        for item_id in removed {
+            self.drop_weapon_item(player_id, item_id, Tracker::Head);
+        }
+    }
+
+    /// Player moves weapon from hand to an empty holster slot.
+    /// The hand needs the swap time before it can shoot again.
+    pub fn holster(&mut self, player_id: usize, hand: Hand, slot: u8) -> Result<(), ()> {
+        let player = &mut self.players[player_id];
+        if player.dead || player.holsters.get(slot as usize) != Some(&None) {return Err(())};
+        let item_id = player.weapon_mut(hand).take().ok_or(())?;
+        player.holsters[slot as usize] = Some(item_id);
+        *player.recharge_milliseconds_mut(hand) = HOLSTER_SWAP_MILLISECONDS;
+        Ok(())
+    }
+
+    /// Player draws weapon from holster slot into an empty hand.
+    /// The weapon needs the swap time before it can shoot.
+    pub fn draw(&mut self, player_id: usize, hand: Hand, slot: u8) -> Result<(), ()> {
+        let player = &mut self.players[player_id];
+        if player.dead || player.weapon_mut(hand).is_some() {return Err(())};
+        let item_id = player.holsters.get_mut(slot as usize).ok_or(())?.take().ok_or(())?;
+        *player.weapon_mut(hand) = Some(item_id);
+        *player.recharge_milliseconds_mut(hand) = HOLSTER_SWAP_MILLISECONDS;
+        Ok(())
+    }
+
+    /// Returns the weapon type in player's hand.
+    pub fn hand_weapon(&self, player_id: usize, hand: Hand) -> Option<usize> {
+        let player = &self.players[player_id];
+        let item_id = match hand {
+            Hand::Left => player.left_weapon,
+            Hand::Right => player.right_weapon,
+        };
+        item_id.map(|item_id| self.weapon_items[item_id].weapon)
+    }
+
+    /// Returns the rounds left in weapon in player's hand,
+    /// if the weapon uses ammo.
+    pub fn ammo_left(&self, player_id: usize, hand: Hand) -> Option<u16> {
+        let player = &self.players[player_id];
+        let item_id = match hand {
+            Hand::Left => player.left_weapon,
+            Hand::Right => player.right_weapon,
+        }?;
+        let item = &self.weapon_items[item_id];
+        self.weapons[item.weapon].magazine?;
+        Some(item.ammo)
+    }
+
+    /// Returns `true` if player can shoot with weapon in hand,
+    /// taking ammo into account.
+    pub fn can_shoot(&self, player_id: usize, hand: Hand) -> bool {
+        self.players[player_id].can_shoot(hand) &&
+        self.ammo_left(player_id, hand) != Some(0)
+    }
+
+    /// Reloads weapon in player's hand from spare ammo.
+    /// The weapon can not shoot until reloading is finished.
+    pub fn reload(&mut self, player_id: usize, hand: Hand) -> Result<(), ()> {
+        let item_id = (*self.players[player_id].weapon_mut(hand)).ok_or(())?;
+        let weapon_id = self.weapon_items[item_id].weapon;
+        let magazine = self.weapons[weapon_id].magazine.ok_or(())?;
+        let reload_milliseconds = self.weapons[weapon_id].reload_milliseconds;
+        let item = &mut self.weapon_items[item_id];
+        let player = &mut self.players[player_id];
+        if player.dead {return Err(())};
+
+        let rounds = magazine.saturating_sub(item.ammo).min(player.spare_ammo);
+        player.spare_ammo -= rounds;
+        item.ammo += rounds;
+        *player.recharge_milliseconds_mut(hand) = reload_milliseconds;
+        Ok(())
+    }
+
+    /// Returns `true` if player is standing at a planet location.
+    pub fn is_at_location(&self, player_id: usize, planet_id: usize, location: u8) -> bool {
+        if self.players[player_id].on_planet != Some(planet_id) {return false};
+        if let Some(head) = self.world_position(player_id, Tracker::Head) {
+            let pos = self.planets[planet_id].location_pos(location);
+            vec3_len(vec3_sub([head[0], 0.0, head[2]], [pos[0], 0.0, pos[2]])) <= LOCATION_RADIUS
+        } else {
+            false
+        }
+    }
+
+    /// Player picks up an ammo pack at a spaceport.
+    /// The player must be at the spaceport location.
+    pub fn pick_up_ammo(
+        &mut self,
+        player_id: usize,
+        planet_id: usize,
+        location: u8
+    ) -> Result<(), ()> {
+        let spaceport_id = self.planets[planet_id].spaceports[location as usize].ok_or(())?;
+        if self.spaceports[spaceport_id].destroyed ||
+           self.spaceports[spaceport_id].ammo_packs == 0 ||
+           !self.is_at_location(player_id, planet_id, location)
+        {
+            return Err(());
+        }
+
+        self.spaceports[spaceport_id].ammo_packs -= 1;
+        let player = &mut self.players[player_id];
+        player.spare_ammo = player.spare_ammo.saturating_add(AMMO_PACK_ROUNDS);
+        Ok(())
+    }
+
+    /// Drops player's weapon by hand.
+    /// On a planet, the weapon lies where the hand was until it despawns.
+    /// Elsewhere it is lost.
+    pub fn drop_weapon(&mut self, player_id: usize, hand: Hand) {
+        if let Some(item_id) = self.players[player_id].weapon_mut(hand).take() {
+            self.drop_weapon_item(player_id, item_id, Tracker::Hand(hand));
+        }
+    }
+
+    /// Puts a weapon item no longer carried by player where a tracked device is.
+    pub fn drop_weapon_item(&mut self, player_id: usize, item_id: usize, tracker: Tracker) {
+        let player = &self.players[player_id];
+        if let (Some(planet_id), None) = (player.on_planet, player.on_spaceship) {
+            if let Some(pos) = self.world_position(player_id, tracker) {
+                self.weapon_items[item_id].lying = Some(LyingItem {
+                    planet: planet_id,
+                    pos,
+                    despawn_milliseconds: WEAPON_DESPAWN_MILLISECONDS,
+                });
+            }
+        }
+    }
+
+    /// Player picks up the nearest weapon lying within reach of an empty hand.
+    pub fn pick_up_weapon(&mut self, player_id: usize, hand: Hand) -> Result<(), ()> {
+        if self.players[player_id].dead ||
+           self.players[player_id].weapon_mut(hand).is_some()
+        {
+            return Err(());
+        }
+        let planet_id = self.players[player_id].on_planet.ok_or(())?;
+        let pos = self.world_position(player_id, Tracker::Hand(hand)).ok_or(())?;
+        let mut nearest: Option<(usize, f64)> = None;
+        for (i, item) in self.weapon_items.iter().enumerate() {
+            if let Some(lying) = item.lying {
+                if lying.planet != planet_id {continue};
+                let dist = vec3_len(vec3_sub(lying.pos, pos));
+                if dist <= PICK_UP_RADIUS && nearest.map(|(_, d)| dist < d).unwrap_or(true) {
+                    nearest = Some((i, dist));
+                }
+            }
+        }
+
+        let (item_id, _) = nearest.ok_or(())?;
+        self.weapon_items[item_id].lying = None;
+        self.weapon_items[item_id].owner = Some(player_id);
+        *self.players[player_id].weapon_mut(hand) = Some(item_id);
+        Ok(())
+    }
+
+    /// Removes weapon item from play.
+    pub fn despawn_weapon_item(&mut self, item_id: usize) {
+        let item = &mut self.weapon_items[item_id];
+        item.owner = None;
+        item.lying = None;
+    }
+
+    /// Ticks down despawn timers of weapons lying around.
+    pub fn update_weapon_items(&mut self, milliseconds: u16) {
+        for item in &mut self.weapon_items {
+            if let Some(ref mut lying) = item.lying {
+                lying.despawn_milliseconds =
+                    lying.despawn_milliseconds.saturating_sub(milliseconds as u32);
+            }
+            if item.lying.map(|lying| lying.despawn_milliseconds == 0).unwrap_or(false) {
+                item.lying = None;
+            }
+        }
+    }
+
+    /// Returns the damage dealt by a single hit with weapon.
+    pub fn roll_damage(&mut self, weapon_id: usize) -> u16 {
+        let firepower = self.weapons[weapon_id].firepower;
+        let variance = self.weapons[weapon_id].damage_variance;
+        if variance == 0 {return firepower};
+        let damage = firepower as i32 + self.rng.next_offset(variance);
+        damage.max(0).min(u16::MAX as i32) as u16
+    }
+
+    /// Deviates a direction randomly by the spread of weapon.
+    pub fn spread(&mut self, weapon_id: usize, dir: [f64; 3]) -> [f64; 3] {
+        let spread_milliradians = self.weapons[weapon_id].spread_milliradians;
+        if spread_milliradians == 0 {return dir};
+        let max_angle = spread_milliradians as f64 / 1000.0;
+        // Sample uniformly within the cone cross section.
+        let angle = max_angle * self.rng.next_f64().sqrt();
+        let phi = 2.0 * ::std::f64::consts::PI * self.rng.next_f64();
+        let helper = if dir[0].abs() < 0.9 {[1.0, 0.0, 0.0]} else {[0.0, 1.0, 0.0]};
+        let u = vec3_normalized(vec3_cross(dir, helper));
+        let v = vec3_cross(dir, u);
+        let offset = vec3_add(vec3_scale(u, phi.cos()), vec3_scale(v, phi.sin()));
+        vec3_normalized(vec3_add(dir, vec3_scale(offset, angle.tan())))
+    }
+
+    /// Player fires weapon in the direction the hand is pointing.
+    /// The first player, spaceship or planet along the ray gets hit,
+    /// unless the weapon fires projectiles.
+    pub fn fire(
+        &mut self,
+        player_id: usize,
+        hand: Hand
+    ) {
+        if let Some(weapon_id) = self.pull_trigger(player_id, hand) {
+            if let Some((origin, dir)) = self.hand_ray(player_id, hand) {
+                let dir = self.spread(weapon_id, dir);
+                if let Some(speed) = self.weapons[weapon_id].projectile_speed {
+                    self.projectiles.push(Projectile {
+                        pos: origin,
+                        vel: vec3_scale(dir, speed),
+                        weapon: weapon_id,
+                        shooter: player_id,
+                        hand,
+                        lifetime_milliseconds: self.weapons[weapon_id]
+                            .projectile_lifetime_milliseconds,
+                    });
+                } else {
+                    let hit = self.cast_ray(origin, dir, Some(player_id));
+                    self.hit_target(player_id, hand, weapon_id, hit);
+                }
+            } else {
+                self.hit_target(player_id, hand, weapon_id, None);
+            }
+        }
+    }
+
+    /// Applies the effect of a weapon hitting a target.
+    pub fn hit_target(
+        &mut self,
+        shooter_id: usize,
+        hand: Hand,
+        weapon_id: usize,
+        hit: Option<RayHit>
+    ) {
+        *self.players[shooter_id].last_hit_mut(hand) = hit.map(|hit| hit.target);
+        if let Some(hit) = hit {
+            self.players[shooter_id].stats.shots_hit += 1;
+            let blast_radius = self.weapons[weapon_id].blast_radius;
+            if blast_radius > 0.0 {
+                if let Target::Planet(planet_id) = hit.target {
+                    self.strike_planet(shooter_id, hand, weapon_id, planet_id);
+                }
+                let damage = self.roll_damage(weapon_id);
+                let damage_type = self.weapons[weapon_id].damage_type;
+                let attack = Attack {attacker: shooter_id, cause: Cause::Weapon(weapon_id), hand: Some(hand)};
+                self.explode(Some(attack), hit.pos, blast_radius, damage, damage_type);
+                return;
+            }
+
+            match hit.target {
+                Target::Player(target_id) => {
+                    let zone = hit.zone.unwrap_or(HitZone::Torso);
+                    self.hit_player(shooter_id, hand, weapon_id, target_id, zone);
+                }
+                Target::Planet(planet_id) => self.strike_planet(shooter_id, hand, weapon_id, planet_id),
+                Target::Spaceship(spaceship_id) => {
+                    let damage = self.roll_damage(weapon_id);
+                    let attack = Attack {attacker: shooter_id, cause: Cause::Weapon(weapon_id), hand: Some(hand)};
+                    self.damage_spaceship(Some(attack), spaceship_id, damage);
+                }
+                Target::Spaceport(spaceport_id) => {
+                    let damage = self.roll_damage(weapon_id);
+                    self.damage_spaceport(spaceport_id, damage);
+                }
+            }
+        }
+    }
+
+    /// Damages spaceship hull by an attack.
+    pub fn damage_spaceship(&mut self, attack: Option<Attack>, spaceship_id: usize, damage: u16) {
+        if !self.combat_allowed() {return};
+        let spaceship = &mut self.spaceships[spaceship_id];
+        spaceship.hull = spaceship.hull.saturating_sub(damage);
+        if spaceship.hull == 0 && !spaceship.destroyed {
+            self.destroy_spaceship(attack, spaceship_id);
+        }
+    }
+
+    /// Destroys spaceship by an attack, killing the players aboard.
+    pub fn destroy_spaceship(&mut self, attack: Option<Attack>, spaceship_id: usize) {
+        self.spaceships[spaceship_id].destroyed = true;
+        for player_id in 0..self.players.len() {
+            if self.players[player_id].on_spaceship == Some(spaceship_id) {
+                self.players[player_id].on_spaceship = None;
+                self.kill_player_by(attack, player_id);
+            }
+        }
+    }
+
+    /// Damages spaceport hull.
+    pub fn damage_spaceport(&mut self, spaceport_id: usize, damage: u16) {
+        if !self.combat_allowed() {return};
+        let spaceport = &mut self.spaceports[spaceport_id];
+        spaceport.hull = spaceport.hull.saturating_sub(damage);
+        if spaceport.hull == 0 {
+            self.destroy_spaceport(spaceport_id);
+        }
+    }
+
+    /// Returns the planet and location of spaceport.
+    pub fn spaceport_location(&self, spaceport_id: usize) -> Option<(usize, u8)> {
+        for (planet_id, planet) in self.planets.iter().enumerate() {
+            for (location, &id) in planet.spaceports.iter().enumerate() {
+                if id == Some(spaceport_id) {
+                    return Some((planet_id, location as u8));
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the position of spaceport in world coordinates.
+    pub fn spaceport_pos(&self, spaceport_id: usize) -> Option<[f64; 3]> {
+        let (planet_id, location) = self.spaceport_location(spaceport_id)?;
+        Some(self.planets[planet_id].location_pos(location))
+    }
+
+    /// Returns the only team with living players at a working spaceport.
+    pub fn spaceport_occupant(&self, spaceport_id: usize) -> Option<usize> {
+        if self.spaceports[spaceport_id].destroyed {return None};
+        let (planet_id, location) = self.spaceport_location(spaceport_id)?;
+        let mut controller = None;
+        for (i, player) in self.players.iter().enumerate() {
+            if player.dead || !self.is_at_location(i, planet_id, location) {continue};
+            match (player.team, controller) {
+                (None, _) => return None,
+                (Some(team_id), None) => controller = Some(team_id),
+                (Some(team_id), Some(id)) => if team_id != id {return None},
+            }
+        }
+        controller
+    }
+
+    /// Returns the team controlling a working spaceport.
+    pub fn spaceport_controller(&self, spaceport_id: usize) -> Option<usize> {
+        let spaceport = &self.spaceports[spaceport_id];
+        if spaceport.destroyed {None} else {spaceport.owner}
+    }
+
+    /// Returns `true` if team controls a working spaceport on planet.
+    pub fn team_has_space_travel(&self, team_id: usize, planet_id: usize) -> bool {
+        self.planets[planet_id].spaceports.iter()
+            .filter_map(|&spaceport_id| spaceport_id)
+            .any(|spaceport_id| self.spaceport_controller(spaceport_id) == Some(team_id))
+    }
+
+    /// Destroys spaceport, which loses its owner.
+    pub fn destroy_spaceport(&mut self, spaceport_id: usize) {
+        let spaceport = &mut self.spaceports[spaceport_id];
+        spaceport.destroyed = true;
+        spaceport.owner = None;
+        spaceport.capturing = None;
+        spaceport.capture_milliseconds = 0;
+    }
+
+    /// Rebuilds destroyed spaceport with a full hull.
+    pub fn rebuild_spaceport(&mut self, spaceport_id: usize) {
+        let spaceport = &mut self.spaceports[spaceport_id];
+        spaceport.destroyed = false;
+        spaceport.hull = DEFAULT_SPACEPORT_HULL;
+    }
+
+    /// Captures spaceports held alone by a team long enough
+    /// and gives points to teams for the spaceports they control.
+    /// Capture progress is lost when the spaceport is contested or left.
+    /// Only game modes capturing spaceports take part.
+    pub fn update_captures(&mut self, milliseconds: u16) {
+        if !self.combat_allowed() || !self.game_mode.captures_spaceports() {return};
+        for i in 0..self.spaceports.len() {
+            if let Some(team_id) = self.spaceport_controller(i) {
+                let team = &mut self.teams[team_id];
+                team.point_milliseconds += milliseconds as u32;
+                team.points += team.point_milliseconds / SPACEPORT_POINT_MILLISECONDS;
+                team.point_milliseconds %= SPACEPORT_POINT_MILLISECONDS;
+            }
+
+            let occupant = self.spaceport_occupant(i);
+            let capture_milliseconds = self.capture_milliseconds;
+            let spaceport = &mut self.spaceports[i];
+            if occupant.is_none() || occupant == spaceport.owner {
+                spaceport.capturing = None;
+                spaceport.capture_milliseconds = 0;
+                continue;
+            }
+            if spaceport.capturing != occupant {
+                spaceport.capturing = occupant;
+                spaceport.capture_milliseconds = 0;
+            }
+            spaceport.capture_milliseconds += milliseconds as u32;
+            if spaceport.capture_milliseconds >= capture_milliseconds {
+                spaceport.owner = occupant;
+                spaceport.capturing = None;
+                spaceport.capture_milliseconds = 0;
+            }
+        }
+    }
+
+    /// Returns the locations on planet that invaders attack,
+    /// which are those with a city or a working spaceport.
+    /// When there are none, invaders gather at location A.
+    pub fn invader_targets(&self, planet_id: usize) -> Vec<u8> {
+        let planet = &self.planets[planet_id];
+        let targets: Vec<u8> = (0..LOCATIONS_PER_PLANET)
+            .filter(|&i| planet.cities[i].is_some() ||
+                         planet.spaceports[i].map(|id| !self.spaceports[id].destroyed) == Some(true))
+            .map(|i| i as u8)
+            .collect();
+        if targets.is_empty() {vec![0]} else {targets}
+    }
+
+    /// Lets a wave of invaders arrive at planet,
+    /// spreading them over the attacked locations.
+    pub fn spawn_wave(&mut self, planet_id: usize, wave: Wave) {
+        let targets = self.invader_targets(planet_id);
+        let mut kinds = vec![InvaderKind::Ship; wave.ships as usize];
+        kinds.extend(vec![InvaderKind::Boarder; wave.boarders as usize]);
+        for (i, kind) in kinds.into_iter().enumerate() {
+            self.invaders.push(Invader {
+                kind,
+                planet: planet_id,
+                location: targets[i % targets.len()],
+                life: match kind {
+                    InvaderKind::Ship => INVADER_SHIP_LIFE,
+                    InvaderKind::Boarder => INVADER_BOARDER_LIFE,
+                },
+                attack_milliseconds: 0,
+            });
+        }
+    }
+
+    /// Kills people in the city at a location on planet,
+    /// recording the casualties per species and for the attacking player.
+    pub fn kill_people(&mut self, planet_id: usize, location: u8, n: u64, attacker: Option<usize>) {
+        if let Some(city_id) = self.planets[planet_id].cities[location as usize] {
+            let mut n = n;
+            for species_id in 0..SPECIES {
+                let population = &mut self.cities[city_id].population[species_id];
+                let killed = n.min(*population);
+                *population -= killed;
+                n -= killed;
+                self.planets[planet_id].casualties[species_id] += killed;
+                if let Some(attacker_id) = attacker {
+                    if killed > 0 {
+                        *self.players[attacker_id].stats.casualties
+                            .entry((planet_id, species_id)).or_insert(0) += killed;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the cities with a working spaceport on planets that are not destroyed.
+    pub fn connected_cities(&self) -> Vec<usize> {
+        (0..self.cities.len())
+            .filter(|&city_id| {
+                self.cities[city_id].planet.map(|planet_id| !self.planets[planet_id].destroyed) ==
+                    Some(true) &&
+                self.city_spaceport(city_id).map(|id| !self.spaceports[id].destroyed) == Some(true)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if a planet destroyer is charging at planet.
+    pub fn is_under_attack(&self, planet_id: usize) -> bool {
+        self.charges.iter().any(|charge| charge.planet == planet_id)
+    }
+
+    /// Moves a part in per mille of a population from a city,
+    /// splitting the people evenly between the destination cities.
+    pub fn move_people(&mut self, from: usize, to: &[usize], population: [u64; SPECIES], per_mille: u64) {
+        if to.is_empty() {return};
+        let per_mille = per_mille.min(1000) as u128;
+        for (species_id, &n) in population.iter().enumerate() {
+            let share = (n as u128 * per_mille / 1000 / to.len() as u128) as u64;
+            for &city_id in to {
+                self.cities[from].population[species_id] -= share;
+                let population = &mut self.cities[city_id].population[species_id];
+                *population = population.saturating_add(share);
+            }
+        }
+    }
+
+    /// Grows population, migrates people between cities with working spaceports
+    /// and evacuates planets under planet destroyer attack.
+    /// Growth less than a person carries over to the next tick.
+    pub fn update_population(&mut self, milliseconds: u16) {
+        self.population_milliseconds += milliseconds as u32;
+        while self.population_milliseconds >= POPULATION_TICK_MILLISECONDS {
+            self.population_milliseconds -= POPULATION_TICK_MILLISECONDS;
+
+            for city in &mut self.cities {
+                for species_id in 0..SPECIES {
+                    let growth = self.species.get(species_id).map(|s| s.growth_per_mille).unwrap_or(0);
+                    let total = city.population[species_id] as u128 * growth as u128 +
+                        city.growth_remainder[species_id] as u128;
+                    city.growth_remainder[species_id] = (total % 1000) as u64;
+                    let born = (total / 1000).min(u64::MAX as u128) as u64;
+                    city.population[species_id] = city.population[species_id].saturating_add(born);
+                }
+            }
+
+            let connected = self.connected_cities();
+            let (attacked, safe): (Vec<usize>, Vec<usize>) = connected.iter()
+                .partition(|&&city_id| self.cities[city_id].planet
+                    .map(|planet_id| self.is_under_attack(planet_id)) == Some(true));
+            for &city_id in &attacked {
+                let population = self.cities[city_id].population;
+                self.move_people(city_id, &safe, population, EVACUATION_PER_MILLE);
+            }
+
+            // People migrate at the same time, based on the populations before migrating.
+            let populations: Vec<[u64; SPECIES]> = safe.iter()
+                .map(|&city_id| self.cities[city_id].population)
+                .collect();
+            for (&city_id, &population) in safe.iter().zip(&populations) {
+                let to: Vec<usize> = safe.iter().cloned().filter(|&id| id != city_id).collect();
+                self.move_people(city_id, &to, population, self.migration_per_mille as u64);
+            }
+        }
+    }
+
+    /// Destroys planet, killing all its people.
+    pub fn destroy_planet(&mut self, planet_id: usize, attacker: Option<usize>) {
+        if self.planets[planet_id].destroyed {return};
+        self.planets[planet_id].destroyed = true;
+        for location in 0..LOCATIONS_PER_PLANET {
+            self.kill_people(planet_id, location as u8, u64::MAX, attacker);
+        }
+        if let Some(attacker_id) = attacker {
+            self.players[attacker_id].stats.planets_destroyed += 1;
+        }
+    }
+
+    /// Invader attacks its location.
+    /// Ships damage the spaceport and kill people, boarders kill people.
+    pub fn invader_attack(&mut self, invader_id: usize) {
+        let invader = self.invaders[invader_id];
+        match invader.kind {
+            InvaderKind::Ship => {
+                let spaceport = self.planets[invader.planet].spaceports[invader.location as usize];
+                if let Some(spaceport_id) = spaceport {
+                    if !self.spaceports[spaceport_id].destroyed {
+                        self.damage_spaceport(spaceport_id, INVADER_SHIP_DAMAGE);
+                    }
+                }
+                self.kill_people(invader.planet, invader.location, INVADER_SHIP_KILLS, None);
+            }
+            InvaderKind::Boarder => {
+                self.kill_people(invader.planet, invader.location, INVADER_BOARDER_KILLS, None);
+            }
+        }
+    }
+
+    /// Lets invaders attack and new waves arrive at the planet
+    /// defended in the game mode.
+    pub fn update_waves(&mut self, milliseconds: u16) {
+        if !self.combat_allowed() {return};
+        let planet_id = if let Some(id) = self.game_mode.defended_planet() {id} else {return};
+        if self.planets[planet_id].destroyed {return};
+
+        for i in 0..self.invaders.len() {
+            self.invaders[i].attack_milliseconds += milliseconds as u32;
+            while self.invaders[i].attack_milliseconds >= INVADER_ATTACK_MILLISECONDS {
+                self.invaders[i].attack_milliseconds -= INVADER_ATTACK_MILLISECONDS;
+                self.invader_attack(i);
+            }
+        }
+
+        self.wave_milliseconds += milliseconds as u32;
+        while let Some(&wave) = self.waves.get(self.waves_arrived) {
+            if self.wave_milliseconds < wave.delay_milliseconds {break};
+            self.wave_milliseconds -= wave.delay_milliseconds;
+            self.waves_arrived += 1;
+            self.spawn_wave(planet_id, wave);
+        }
+    }
+
+    /// Returns `true` if every wave has arrived and every invader is killed.
+    pub fn waves_defeated(&self) -> bool {
+        !self.waves.is_empty() &&
+        self.waves_arrived == self.waves.len() &&
+        self.invaders.is_empty()
+    }
+
+    /// Returns `true` if spaceship is in orbit of planet.
+    pub fn in_orbit(&self, spaceship_id: usize, planet_id: usize) -> bool {
+        let planet = &self.planets[planet_id];
+        let dist = vec3_len(vec3_sub(self.spaceships[spaceship_id].pos, planet.pos));
+        dist <= planet.radius + ORBIT_ALTITUDE
+    }
+
+    /// Player shoots at an invader at a location on planet.
+    /// The player must be on the planet or aboard a spaceship in orbit of it.
+    /// Hits deal torso damage, reduced by the invader's resistance.
+    pub fn shoot_at_invader(
+        &mut self,
+        shooter_id: usize,
+        hand: Hand,
+        planet_id: usize,
+        location: u8
+    ) -> Result<(), ()> {
+        let player = &self.players[shooter_id];
+        let in_range = player.on_planet == Some(planet_id) ||
+            player.on_spaceship.map(|id| self.in_orbit(id, planet_id)) == Some(true);
+        if player.dead || !in_range {return Err(())};
+
+        if let Some(weapon_id) = self.pull_trigger(shooter_id, hand) {
+            // Roll for hit.
+            let accuracy_percent = self.weapons[weapon_id].accuracy_percent;
+            if accuracy_percent < 100 &&
+               self.rng.next_u64() % 100 >= accuracy_percent as u64
+            {
+                return Ok(());
+            }
+
+            if let Some(i) = self.invaders.iter()
+                .position(|invader| invader.planet == planet_id && invader.location == location)
+            {
+                let damage_type = self.weapons[weapon_id].damage_type;
+                let resistance = self.invaders[i].kind.resistance_percent()[damage_type as usize] as u32;
+                let damage = self.roll_damage(weapon_id) as u32 * self.torso_damage_percent as u32 / 100 *
+                    (100 - resistance.min(100)) / 100;
+                let invader = &mut self.invaders[i];
+                invader.life = invader.life.saturating_sub(damage.min(u16::MAX as u32) as u16);
+                self.players[shooter_id].stats.shots_hit += 1;
+                if invader.life == 0 {
+                    self.invaders.remove(i);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the spaceports controlled by team.
+    pub fn controlled_spaceports(&self, team_id: usize) -> Vec<usize> {
+        (0..self.spaceports.len())
+            .filter(|&i| self.spaceport_controller(i) == Some(team_id))
+            .collect()
+    }
+
+    /// Explodes at a position, damaging every player, spaceship and spaceport
+    /// within the blast radius.
+    /// Damage falls off linearly with distance from the center.
+    /// Players are damaged following the friendly fire rules for the attacker.
+    /// Each damaged target is reported in the event log.
+    pub fn explode(
+        &mut self,
+        attack: Option<Attack>,
+        pos: [f64; 3],
+        radius: f64,
+        damage: u16,
+        damage_type: DamageType
+    ) {
+        let falloff = |dist: f64, percent: u32| -> Option<u16> {
+            if dist > radius {return None};
+            let damage = (damage as f64 * (1.0 - dist / radius)).round() as u32 * percent / 100;
+            if damage == 0 {None} else {Some(damage as u16)}
+        };
+
+        for i in 0..self.players.len() {
+            if self.players[i].dead {continue};
+            let percent = attack.map(|attack| self.damage_percent(attack.attacker, i)).unwrap_or(100);
+            if let Some(head) = self.world_position(i, Tracker::Head) {
+                if let Some(damage) = falloff(vec3_len(vec3_sub(head, pos)), percent) {
+                    self.damage_player_by(attack, i, damage, damage_type);
+                    self.events.push(Event::BlastDamage {target: Target::Player(i), damage});
+                }
+            }
+        }
+        for i in 0..self.spaceships.len() {
+            if self.spaceships[i].destroyed {continue};
+            let dist = (vec3_len(vec3_sub(self.spaceships[i].pos, pos)) - SPACESHIP_RADIUS).max(0.0);
+            if let Some(damage) = falloff(dist, 100) {
+                self.damage_spaceship(attack, i, damage);
+                self.events.push(Event::BlastDamage {target: Target::Spaceship(i), damage});
+            }
+        }
+        for i in 0..self.spaceports.len() {
+            if self.spaceports[i].destroyed {continue};
+            if let Some(spaceport_pos) = self.spaceport_pos(i) {
+                if let Some(damage) = falloff(vec3_len(vec3_sub(spaceport_pos, pos)), 100) {
+                    self.damage_spaceport(i, damage);
+                    self.events.push(Event::BlastDamage {target: Target::Spaceport(i), damage});
+                }
+            }
+        }
+    }
+
+    /// Player aboard spaceship fires canon in slot at a planet location.
+    /// Canons without blast radius only hit the spaceport at the location.
+    /// The canon kills people in the city at the location.
+    pub fn fire_canon(
+        &mut self,
+        player_id: usize,
+        canon_slot: CanonSlot,
+        planet_id: usize,
+        location: u8
+    ) -> Result<(), ()> {
+        if self.players[player_id].dead {return Err(())};
+        let spaceship_id = self.players[player_id].on_spaceship.ok_or(())?;
+        if self.spaceships[spaceship_id].destroyed {return Err(())};
+        let canon_id = (*self.spaceships[spaceship_id].canon_mut(canon_slot)).ok_or(())?;
+        let firepower = self.canons[canon_id].firepower;
+        let blast_radius = self.canons[canon_id].blast_radius;
+        if self.planets[planet_id].destroyed {return Ok(())};
+
+        if blast_radius > 0.0 {
+            let pos = self.planets[planet_id].location_pos(location);
+            let attack = Attack {attacker: player_id, cause: Cause::Canon(canon_id), hand: None};
+            self.explode(Some(attack), pos, blast_radius, firepower, DamageType::Kinetic);
+        } else if let Some(spaceport_id) = self.planets[planet_id].spaceports[location as usize] {
+            self.damage_spaceport(spaceport_id, firepower);
+        }
+        let casualties = firepower as u64 * CANON_CASUALTIES_PER_1000_DAMAGE / 1000;
+        self.kill_people(planet_id, location, casualties, Some(player_id));
+        Ok(())
+    }
+
+    /// Moves projectiles and resolves their hits.
+    pub fn update_projectiles(&mut self, milliseconds: u16) {
+        let dt = milliseconds as f64 / 1000.0;
+        let mut i = 0;
+        while i < self.projectiles.len() {
+            let (pos, vel, shooter) = {
+                let p = &self.projectiles[i];
+                (p.pos, p.vel, p.shooter)
+            };
+            let step = vec3_scale(vel, dt);
+            let dist = vec3_len(step);
+            let hit = if dist > 0.0 {
+                match self.cast_ray(pos, vec3_normalized(step), Some(shooter)) {
+                    Some(hit) if hit.distance <= dist => Some(hit),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+            if hit.is_some() {
+                let p = self.projectiles.swap_remove(i);
+                self.hit_target(p.shooter, p.hand, p.weapon, hit);
+                continue;
+            }
+
+            let p = &mut self.projectiles[i];
+            p.pos = vec3_add(pos, step);
+            if milliseconds as u32 >= p.lifetime_milliseconds {
+                self.projectiles.swap_remove(i);
+            } else {
+                p.lifetime_milliseconds -= milliseconds as u32;
+                i += 1;
+            }
+        }
+    }
+
+    /// Advances the simulation clock with an amount of milliseconds.
+    pub fn update_milliseconds(&mut self, milliseconds: u16) {
+        self.milliseconds += milliseconds as u64;
+        self.recharge_milliseconds_all_weapons(milliseconds);
+        self.update_projectiles(milliseconds);
+        self.regenerate(milliseconds);
+        self.update_status_effects(milliseconds);
+        self.update_weapon_items(milliseconds);
+        self.update_population(milliseconds);
+        self.update_charges(milliseconds);
+        self.update_captures(milliseconds);
+        self.update_waves(milliseconds);
+        self.update_phase(milliseconds);
+    }
+
+    /// Returns the origin and direction of the ray pointing out from hand.
+    /// A hand points along the negative Z axis when the orientation is identity.
+    pub fn hand_ray(&self, player_id: usize, hand: Hand) -> Option<([f64; 3], [f64; 3])> {
+        let origin = self.world_position(player_id, Tracker::Hand(hand))?;
+        let orientation = self.players[player_id].pose(Tracker::Hand(hand)).orientation;
+        let dir = quaternion::rotate_vector(orientation, [0.0, 0.0, -1.0]);
+        Some((origin, vec3_normalized(vec3_cast(dir))))
+    }
+
+    /// Returns the first target along a ray.
+    pub fn cast_ray(
+        &self,
+        origin: [f64; 3],
+        dir: [f64; 3],
+        ignore_player: Option<usize>
+    ) -> Option<RayHit> {
+        let mut hit: Option<RayHit> = None;
+        {
+            let mut test = |target: Target, zone: Option<HitZone>, t: Option<f64>| {
+                if let Some(t) = t {
+                    if hit.map(|hit| t < hit.distance).unwrap_or(true) {
+                        hit = Some(RayHit {
+                            target,
+                            zone,
+                            distance: t,
+                            pos: vec3_add(origin, vec3_scale(dir, t)),
+                        });
+                    }
+                }
+            };
+            for i in 0..self.players.len() {
+                if Some(i) == ignore_player || self.players[i].dead {continue};
+                if let Some((zone, t)) = self.ray_player(origin, dir, i) {
+                    test(Target::Player(i), Some(zone), Some(t));
+                }
+            }
+            for (i, spaceship) in self.spaceships.iter().enumerate() {
+                if spaceship.destroyed {continue};
+                test(Target::Spaceship(i), None,
+                     ray_sphere(origin, dir, spaceship.pos, SPACESHIP_RADIUS));
+            }
+            for (i, planet) in self.planets.iter().enumerate() {
+                if planet.destroyed {continue};
+                test(Target::Planet(i), None,
+                     ray_sphere(origin, dir, planet.pos, planet.radius));
+            }
+        }
+        hit
+    }
+
+    /// Returns the first zone of player hit by a ray and the distance to it.
+    pub fn ray_player(
+        &self,
+        origin: [f64; 3],
+        dir: [f64; 3],
+        player_id: usize
+    ) -> Option<(HitZone, f64)> {
+        let head = self.world_position(player_id, Tracker::Head)?;
+        let head_height = self.players[player_id].head.pos[1] as f64;
+        let feet = vec3_sub(head, [0.0, head_height, 0.0]);
+        let neck = vec3_sub(head, [0.0, HEAD_RADIUS + PLAYER_RADIUS, 0.0]);
+        let mut hits = vec![
+            (HitZone::Head, ray_sphere(origin, dir, head, HEAD_RADIUS)),
+            (HitZone::Torso, ray_capsule(origin, dir, feet, neck, PLAYER_RADIUS)),
+        ];
+        for &hand in &[Hand::Left, Hand::Right] {
+            let pos = self.world_position(player_id, Tracker::Hand(hand))?;
+            hits.push((HitZone::Hand(hand), ray_sphere(origin, dir, pos, HAND_RADIUS)));
+        }
+        let mut first: Option<(HitZone, f64)> = None;
+        for (zone, t) in hits {
+            if let Some(t) = t {
+                if first.map(|(_, min)| t < min).unwrap_or(true) {
+                    first = Some((zone, t));
+                }
+            }
+        }
+        first
+    }
+
+    /// Player hits planet with weapon.
+    pub fn hit_planet(&mut self, shooter_id: usize, weapon_id: usize, planet_id: usize) {
+        if self.weapons[weapon_id].planet_destroyer && self.combat_allowed() {
+            self.destroy_planet(planet_id, Some(shooter_id));
+        }
+    }
+
+    /// Kills player without a cause.
+    pub fn kill_player(&mut self, player_id: usize) {
+        self.kill_player_by(None, player_id);
+    }
+
+    /// Kills player by an attack.
+    pub fn kill_player_by(&mut self, attack: Option<Attack>, player_id: usize) {
+        if !self.players[player_id].dead {
+            self.players[player_id].dead = true;
+            self.record_death(player_id, attack);
+        }
+    }
+
+    /// Records the death of player by an attack in the statistics,
+    /// crediting the killer and the players who recently damaged the victim.
+    pub fn record_death(&mut self, victim_id: usize, attack: Option<Attack>) {
+        let killer = attack.map(|attack| attack.attacker);
+        let since = self.milliseconds.saturating_sub(ASSIST_MILLISECONDS);
+        let mut assists = vec![];
+        for &(attacker_id, time) in &self.players[victim_id].damage_taken {
+            if time >= since && Some(attacker_id) != killer && !assists.contains(&attacker_id) {
+                assists.push(attacker_id);
+            }
+        }
+        for &attacker_id in &assists {
+            self.players[attacker_id].stats.assists += 1;
+        }
+        if let Some(killer_id) = killer {
+            if killer_id != victim_id {
+                self.players[killer_id].stats.kills += 1;
+            }
+        }
+        let victim = &mut self.players[victim_id];
+        victim.stats.deaths += 1;
+        victim.damage_taken.clear();
+        victim.death = Some(Death {attack, assists});
+    }
+
+    /// Damages player by an attack, recording it in the statistics.
+    ///
+    /// Damage is resolved through shield, then armor, then life.
+    /// While shield is up, it reduces the damage by its resistance
+    /// and absorbs the rest up to its capacity.
+    /// Damage overflowing a broken shield passes through unreduced.
+    /// Armor absorbs its resistance of the remaining damage,
+    /// wearing down by the absorbed amount.
+    pub fn damage_player_by(
+        &mut self,
+        attack: Option<Attack>,
+        target_id: usize,
+        damage: u16,
+        damage_type: DamageType
+    ) {
+        if !self.combat_allowed() {return};
+        let now = self.milliseconds;
+        let player = &mut self.players[target_id];
+        let (life, armor, shield, was_dead) = (player.life, player.armor, player.shield, player.dead);
+        if let Some(attack) = attack {
+            if attack.attacker != target_id && damage > 0 && !was_dead {
+                player.damage_taken.push((attack.attacker, now));
+            }
+        }
+        let mut damage = damage as u32;
+
+        if player.shield > 0 {
+            let resistance = (player.shield_resistance_percent[damage_type as usize] as u32).min(100);
+            let reduced = damage * (100 - resistance) / 100;
+            if reduced <= player.shield as u32 {
+                player.shield -= reduced as u16;
+                damage = 0;
+            } else {
+                // The part of the hit covered by shield before it broke.
+                let covered = (player.shield as u32 * 100).div_ceil(100 - resistance);
+                player.shield = 0;
+                damage -= covered.min(damage);
+            }
+        }
+        player.milliseconds_since_damage = 0;
+
+        if player.armor > 0 {
+            let resistance = player.armor_resistance_percent[damage_type as usize] as u32;
+            let absorbed = (damage * resistance.min(100) / 100).min(player.armor as u32);
+            player.armor -= absorbed as u16;
+            damage -= absorbed;
+        }
+
+        if damage >= player.life as u32 {
+            player.life = 0;
+            player.dead = true;
+        } else {
+            player.life -= damage as u16;
+        }
+
+        let player = &self.players[target_id];
+        if player.life < life || player.armor < armor || player.shield < shield {
+            self.interrupt_charge(target_id);
+        }
+        if was_dead {return};
+        let life_lost = (life - self.players[target_id].life) as u32;
+        if let Some(attack) = attack {
+            if attack.attacker != target_id {
+                let stats = &mut self.players[attack.attacker].stats;
+                *stats.damage.entry(attack.cause).or_insert(0) += life_lost;
+            }
+        }
+        if self.players[target_id].dead {
+            self.record_death(target_id, attack);
+        }
+    }
+
+    /// Regenerates shield and life of players that have not taken damage recently.
+    pub fn regenerate(&mut self, milliseconds: u16) {
+        let max_life = self.max_life;
+        for player in &mut self.players {
+            if player.dead {continue};
+            let before = player.milliseconds_since_damage;
+            let after = before.saturating_add(milliseconds as u32);
+            player.milliseconds_since_damage = after;
+
+            let regen = World::regeneration(SHIELD_REGENERATION_PER_SECOND,
+                SHIELD_REGENERATION_DELAY_MILLISECONDS, before, after);
+            let shield = (player.shield as u32 + regen).min(player.max_shield as u32);
+            player.shield = player.shield.max(shield as u16);
+
+            let regen = World::regeneration(LIFE_REGENERATION_PER_SECOND,
+                LIFE_REGENERATION_DELAY_MILLISECONDS, before, after);
+            let life = (player.life as u32 + regen).min(max_life as u32);
+            player.life = player.life.max(life as u16);
+        }
+    }
+
+    /// Returns how much regenerates between two times since damage.
+    ///
+    /// Computed from the total regenerated since the delay,
+    /// so short frames add up without losing fractions.
+    fn regeneration(per_second: u16, delay: u32, before: u32, after: u32) -> u32 {
+        let total = |ms: u32| per_second as u64 * ms.saturating_sub(delay) as u64 / 1000;
+        (total(after) - total(before)) as u32
+    }
+
+    /// Heals player, up to max life.
+    pub fn heal_player(&mut self, player_id: usize, amount: u16) {
+        let player = &mut self.players[player_id];
+        if player.dead {return};
+        let life = (player.life as u32 + amount as u32).min(self.max_life as u32);
+        player.life = player.life.max(life as u16);
+    }
+
+    /// Returns `true` if two different players fight on the same or allied sides.
+    pub fn are_teammates(&self, a: usize, b: usize) -> bool {
+        if a == b {return false};
+        match (self.players[a].side(), self.players[b].side()) {
+            (Some(a), Some(b)) => self.are_allied_sides(a, b),
+            _ => false,
+        }
+    }
+
+    /// Player heals a teammate with healing tool.
+    /// The teammate must be within healing range.
+    /// A heal counts as a hit in the healer's accuracy.
+    pub fn heal(
+        &mut self,
+        healer_id: usize,
+        hand: Hand,
+        target_id: usize
+    ) -> Result<(), ()> {
+        let weapon_id = self.hand_weapon(healer_id, hand).ok_or(())?;
+        let healing = self.weapons[weapon_id].healing;
+        if healing == 0 || !self.are_teammates(healer_id, target_id) {return Err(())};
+        match self.player_distance(healer_id, target_id) {
+            Some(distance) if distance <= HEALING_RANGE => {}
+            _ => return Err(()),
+        }
+
+        if self.pull_trigger(healer_id, hand).is_some() {
+            self.players[healer_id].stats.shots_hit += 1;
+            self.heal_player(target_id, healing);
+        }
+        Ok(())
+    }
+
+    /// Player picks up a medkit in a city.
+    /// The player must be at the city location.
+    pub fn pick_up_medkit(&mut self, player_id: usize, city_id: usize) -> Result<(), ()> {
+        let planet_id = self.cities[city_id].planet.ok_or(())?;
+        let location = self.cities[city_id].location.ok_or(())?;
+        if self.cities[city_id].medkits == 0 ||
+           !self.is_at_location(player_id, planet_id, location)
+        {
+            return Err(());
+        }
+
+        self.cities[city_id].medkits -= 1;
+        self.heal_player(player_id, MEDKIT_LIFE);
+        Ok(())
+    }
+
+    /// Player shoots at nothing.
+    pub fn shoot_at_nothing(
+        &mut self,
+        player_id: usize,
+        hand: Hand
+    ) {
+        self.pull_trigger(player_id, hand);
+    }
+
+    /// Pulls the trigger of the weapon in player's hand.
+    /// Returns the weapon type if it fires.
+    pub fn pull_trigger(&mut self, player_id: usize, hand: Hand) -> Option<usize> {
+        let item_id = (*self.players[player_id].weapon_mut(hand))?;
+        let weapon_id = self.weapon_items[item_id].weapon;
+        if self.players[player_id].has_status(StatusEffect::Stunned) {return None};
+
+        // Consume ammo.
+        if self.weapons[weapon_id].magazine.is_some() {
+            let ammo = &mut self.weapon_items[item_id].ammo;
+            if *ammo == 0 {return None};
+            *ammo -= 1;
+        }
+
+        // Recharge weapon.
+        let recharge_milliseconds = self.weapons[weapon_id].recharge_milliseconds;
+        *self.players[player_id].recharge_milliseconds_mut(hand) = recharge_milliseconds;
+        self.players[player_id].stats.shots_fired += 1;
+        Some(weapon_id)
+    }
+
+    /// Recharges weapon with an amount of milliseconds.
+    pub fn recharge_milliseconds(
+        &mut self,
+        player_id: usize,
+        hand: Hand,
+        recharge_milliseconds: u16
+    ) {
+        let recharge_milliseconds = self.players[player_id].recharge_rate(recharge_milliseconds);
+        let ms = self.players[player_id].recharge_milliseconds_mut(hand);
+        if recharge_milliseconds > *ms {
+            *ms = 0;
+        } else {
+            *ms -= recharge_milliseconds;
+        }
+    }
+
+    /// Recharges all weapons with an amount of milliseconds.
+    pub fn recharge_milliseconds_all_weapons(
+        &mut self,
+        recharge_milliseconds: u16
+    ) {
+        for player in &mut self.players {
+            let recharge_milliseconds = player.recharge_rate(recharge_milliseconds);
+            if recharge_milliseconds > player.left_recharge_milliseconds {
+                player.left_recharge_milliseconds = 0;
+            } else {
+                player.left_recharge_milliseconds -= recharge_milliseconds;
+            }
+            if recharge_milliseconds > player.right_recharge_milliseconds {
+                player.right_recharge_milliseconds = 0;
+            } else {
+                player.right_recharge_milliseconds -= recharge_milliseconds;
+            }
+        }
+    }
+
+    /// Spawns player at the spawn point chosen by the game mode,
+    /// or on its spawning planet.
+    /// Dead players respawn when the game mode allows it.
+    pub fn spawn(
+        &mut self,
+        player_id: usize
+    ) -> Result<(), ()> {
+        if self.players[player_id].dead {
+            if !self.game_mode.can_respawn(self, player_id) {return Err(())};
+            self.revive(player_id);
+        }
+        let spawn_point = self.game_mode.spawn_point(self, player_id).or_else(||
+            self.players[player_id].spawning_planet(self).map(|planet_id| (planet_id, 0)));
+        if let Some((planet_id, location)) = spawn_point {
+            self.players[player_id].on_planet = Some(planet_id);
+            self.players[player_id].location = location;
+            self.players[player_id].on_spaceship = None;
+            self.apply_loadout(player_id)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the duration of a timed phase.
+    pub fn phase_duration_mut(&mut self, phase: Phase) -> Option<&mut u32> {
+        match phase {
+            Phase::Warmup => Some(&mut self.warmup_milliseconds),
+            Phase::Countdown => Some(&mut self.countdown_milliseconds),
+            Phase::InProgress => Some(&mut self.match_milliseconds),
+            Phase::Overtime => Some(&mut self.overtime_milliseconds),
+            Phase::Lobby | Phase::Ended => None,
+        }
+    }
+
+    /// Returns `true` if players can shoot and take damage.
+    pub fn combat_allowed(&self) -> bool {
+        match self.phase {
+            None | Some(Phase::Warmup) | Some(Phase::InProgress) | Some(Phase::Overtime) => true,
+            Some(Phase::Lobby) | Some(Phase::Countdown) | Some(Phase::Ended) => false,
+        }
+    }
+
+    /// Returns `true` if players can spawn.
+    pub fn spawn_allowed(&self) -> bool {
+        !matches!(self.phase, Some(Phase::Lobby) | Some(Phase::Ended))
+    }
+
+    /// Returns `true` if players can choose teams, loadouts and game mode.
+    pub fn lobby_open(&self) -> bool {
+        matches!(self.phase, None | Some(Phase::Lobby))
+    }
+
+    /// Opens the lobby for a new match.
+    pub fn open_lobby(&mut self) -> Result<(), ()> {
+        match self.phase {
+            None | Some(Phase::Ended) => {
+                self.phase = Some(Phase::Lobby);
+                self.phase_milliseconds = 0;
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+
+    /// Starts the match from the lobby.
+    pub fn start_match(&mut self) -> Result<(), ()> {
+        if self.phase != Some(Phase::Lobby) {return Err(())};
+        self.enter_phase(Phase::Warmup);
+        Ok(())
+    }
+
+    /// Enters a phase, skipping timed phases that have no duration.
+    pub fn enter_phase(&mut self, phase: Phase) {
+        let mut phase = phase;
+        loop {
+            let duration = self.phase_duration_mut(phase).map(|ms| *ms);
+            match duration {
+                Some(0) => phase = phase.next(self.overtime_milliseconds > 0),
+                Some(ms) => {
+                    self.phase_milliseconds = ms;
+                    break;
+                }
+                None => {
+                    self.phase_milliseconds = 0;
+                    break;
+                }
+            }
+        }
+        self.phase = Some(phase);
+    }
+
+    /// Counts down the time of the current phase and moves on when it runs out.
+    pub fn update_phase(&mut self, milliseconds: u16) {
+        let mut milliseconds = milliseconds as u32;
+        while let Some(phase) = self.phase {
+            if self.phase_duration_mut(phase).is_none() {break};
+            if milliseconds < self.phase_milliseconds {
+                self.phase_milliseconds -= milliseconds;
+                break;
+            }
+            milliseconds -= self.phase_milliseconds;
+            let overtime = self.overtime_milliseconds > 0 &&
+                           self.game_mode.winners(self).is_empty();
+            self.enter_phase(phase.next(overtime));
+        }
+    }
+
+    /// Ends the match when the game mode has decided a winner.
+    pub fn end_match_if_decided(&mut self) {
+        let playing = matches!(self.phase, Some(Phase::InProgress) | Some(Phase::Overtime));
+        if playing && !self.game_mode.winners(self).is_empty() {
+            self.enter_phase(Phase::Ended);
+        }
+    }
+
+    /// Brings a dead player back with full life and shield.
+    pub fn revive(&mut self, player_id: usize) {
+        let max_life = self.max_life;
+        let player = &mut self.players[player_id];
+        player.dead = false;
+        player.life = max_life;
+        player.shield = player.max_shield;
+        player.milliseconds_since_damage = 0;
+        player.status_milliseconds = [0; STATUS_EFFECTS];
+        player.status_attacks = [None; STATUS_EFFECTS];
+        player.burning_remainder = 0;
+    }
+
+    /// Player boards spaceship.
+    pub fn board_spaceship(
+        &mut self,
+        player_id: usize,
+        spaceship_id: usize
+    ) {
+        self.players[player_id].on_spaceship = Some(spaceship_id);
+        self.players[player_id].on_planet = None;
+    }
+
+    /// Updates the pose of a tracked device.
+    pub fn update_pose(
+        &mut self,
+        player_id: usize,
+        tracker: Tracker,
+        pose: Pose
+    ) {
+        *self.players[player_id].pose_mut(tracker) = pose;
+    }
+
+    /// Returns the origin of the frame that player poses are relative to.
+    pub fn player_frame_origin(&self, player_id: usize) -> Option<[f64; 3]> {
+        let player = &self.players[player_id];
+        if let Some(spaceship_id) = player.on_spaceship {
+            Some(self.spaceships[spaceship_id].pos)
+        } else {
+            player.on_planet.map(|planet_id| self.planets[planet_id].location_pos(player.location))
+        }
+    }
+
+    /// Returns the position of a tracked device in world coordinates.
+    pub fn world_position(&self, player_id: usize, tracker: Tracker) -> Option<[f64; 3]> {
+        let origin = self.player_frame_origin(player_id)?;
+        let pos = self.players[player_id].pose(tracker).pos;
+        Some(vec3_add(origin, vec3_cast(pos)))
+    }
+
+    /// Returns the distance in meters between the heads of two players.
+    pub fn player_distance(&self, a: usize, b: usize) -> Option<f64> {
+        let a = self.world_position(a, Tracker::Head)?;
+        let b = self.world_position(b, Tracker::Head)?;
+        Some(vec3_len(vec3_sub(a, b)))
     }
 }